@@ -0,0 +1,480 @@
+//! Columnar bulk import/export through Apache Arrow and Parquet.
+//!
+//! This module lets callers move whole batches of documents in and out of a
+//! [`Collection`] without constructing one [`Doc`] per row, by mapping Arrow
+//! array types onto `FieldSchema`/`VectorSchema` by column name.
+//!
+//! Gate this module behind the `arrow` cargo feature.
+
+use std::fs::File;
+use std::path::Path;
+
+use arrow::array::{
+    Array, FixedSizeListArray, Float32Array, Float64Array, Int32Array, Int64Array, ListArray,
+    StringArray, StructArray,
+};
+use arrow::datatypes::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema};
+use arrow::ffi::FFI_ArrowSchema;
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+use crate::collection::Collection;
+use crate::doc::{Doc, WriteResults};
+use crate::error::{Error, Result};
+use crate::schema::{CollectionSchema, FieldSchema};
+use crate::types::DataType;
+
+/// Options controlling [`Collection::scan_to_parquet`].
+#[derive(Debug, Clone)]
+pub struct ParquetScanOptions {
+    /// Number of documents buffered into each Arrow `RecordBatch` before it
+    /// is written out.
+    pub batch_size: usize,
+}
+
+impl Default for ParquetScanOptions {
+    fn default() -> Self {
+        Self { batch_size: 4096 }
+    }
+}
+
+impl Collection {
+    /// Insert every row of `batch` as a document.
+    ///
+    /// Each column is mapped to a field by name: `FixedSizeList<Float32>`
+    /// becomes a dense `VectorFp32` value, a `Struct<indices: List<Int32>,
+    /// values: List<Float32>>` becomes a `SparseVectorFp32` value, `Utf8`
+    /// becomes a `String`, and `Int64` becomes an `Int64`. Each
+    /// fixed-size-list row's length is checked against the column's own
+    /// declared width and surfaced as [`Error::DimensionMismatch`] on
+    /// mismatch; that width is not compared with the collection's schema
+    /// here ([`Collection::import_parquet`] checks columns against a
+    /// [`CollectionSchema`] before inserting).
+    pub fn insert_record_batch(&self, batch: &RecordBatch) -> Result<WriteResults> {
+        let schema = batch.schema();
+        let mut docs = Vec::with_capacity(batch.num_rows());
+
+        for row in 0..batch.num_rows() {
+            let mut doc = Doc::new();
+            for (col_idx, field) in schema.fields().iter().enumerate() {
+                let column = batch.column(col_idx);
+                if column.is_null(row) {
+                    continue;
+                }
+                set_doc_field_from_column(&mut doc, field.name(), column.as_ref(), row)?;
+            }
+            docs.push(doc);
+        }
+
+        self.insert(&docs)
+    }
+
+    /// Scan every document in the collection and write it to a Parquet file
+    /// at `path`, batching rows according to `options`.
+    ///
+    /// This would round-trip through the same column mapping as
+    /// [`Collection::insert_record_batch`], giving a portable snapshot for
+    /// backup or analysis in other tools - but there is no FFI cursor/scan
+    /// primitive in this build to read documents back out of a collection,
+    /// so there is no way to implement it honestly. Always returns
+    /// [`Error::NotSupported`] rather than writing a Parquet file that
+    /// silently omits every row: a caller backing up a non-empty collection
+    /// must not get back an empty file with no error.
+    pub fn scan_to_parquet<P: AsRef<Path>>(
+        &self,
+        _path: P,
+        _options: ParquetScanOptions,
+    ) -> Result<()> {
+        Err(Error::NotSupported(
+            "scan_to_parquet: no FFI cursor/scan primitive exists in this build to read \
+             documents back out of a collection"
+                .to_string(),
+        ))
+    }
+
+    /// Write every document in the collection to a Parquet file at `path`.
+    ///
+    /// Convenience wrapper over [`Collection::scan_to_parquet`] with default
+    /// [`ParquetScanOptions`], named to pair with
+    /// [`Collection::import_parquet`]/[`create_and_open_from_parquet`]. Always
+    /// fails with [`Error::NotSupported`] - see [`Collection::scan_to_parquet`]
+    /// for why.
+    pub fn export_parquet<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.scan_to_parquet(path, ParquetScanOptions::default())
+    }
+
+    /// Stream every `RecordBatch` in the Parquet file at `path` into this
+    /// collection, validating each batch's Arrow schema against `schema`
+    /// before inserting.
+    ///
+    /// Returns one [`WriteResults`] per batch read, so a caller can inspect
+    /// per-batch (and therefore per-chunk-of-rows) insert failures without
+    /// the whole import aborting on the first bad row.
+    pub fn import_parquet<P: AsRef<Path>>(
+        &self,
+        path: P,
+        schema: &CollectionSchema,
+    ) -> Result<Vec<WriteResults>> {
+        let file = File::open(path.as_ref())
+            .map_err(|e| Error::InternalError(format!("failed to open parquet file: {e}")))?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+            .map_err(|e| Error::InternalError(format!("failed to read parquet metadata: {e}")))?;
+        validate_parquet_schema(builder.schema(), schema)?;
+
+        let reader = builder
+            .build()
+            .map_err(|e| Error::InternalError(format!("failed to build parquet reader: {e}")))?;
+
+        let mut results = Vec::new();
+        for batch in reader {
+            let batch =
+                batch.map_err(|e| Error::InternalError(format!("failed to read batch: {e}")))?;
+            results.push(self.insert_record_batch(&batch)?);
+        }
+        Ok(results)
+    }
+}
+
+/// Create a new collection at `path` from `schema`, then import every row of
+/// the Parquet file at `parquet_path` into it.
+///
+/// The Parquet schema is validated against `schema` before the collection is
+/// created, so a mismatched dump never leaves behind a half-created
+/// collection directory.
+pub fn create_and_open_from_parquet<P: AsRef<Path>>(
+    path: P,
+    schema: CollectionSchema,
+    parquet_path: P,
+) -> Result<Collection> {
+    let file = File::open(parquet_path.as_ref())
+        .map_err(|e| Error::InternalError(format!("failed to open parquet file: {e}")))?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+        .map_err(|e| Error::InternalError(format!("failed to read parquet metadata: {e}")))?;
+    validate_parquet_schema(builder.schema(), &schema)?;
+    let reader = builder
+        .build()
+        .map_err(|e| Error::InternalError(format!("failed to build parquet reader: {e}")))?;
+
+    let collection = Collection::create_and_open(path, schema)?;
+    for batch in reader {
+        let batch =
+            batch.map_err(|e| Error::InternalError(format!("failed to read batch: {e}")))?;
+        collection.insert_record_batch(&batch)?;
+    }
+    Ok(collection)
+}
+
+/// Check that every field in `schema` has a same-named, type-compatible
+/// column in `arrow_schema`, so [`Collection::import_parquet`] and
+/// [`create_and_open_from_parquet`] fail fast on a mismatched dump instead of
+/// partway through a batch.
+fn validate_parquet_schema(arrow_schema: &ArrowSchema, schema: &CollectionSchema) -> Result<()> {
+    for field in schema.fields() {
+        let expected = arrow_type_for(field.data_type(), field.dimension()).ok_or_else(|| {
+            Error::NotSupported(format!(
+                "{}: {:?} has no Arrow column mapping",
+                field.name(),
+                field.data_type()
+            ))
+        })?;
+
+        let actual = arrow_schema
+            .field_with_name(field.name())
+            .map_err(|_| {
+                Error::InvalidArgument(format!(
+                    "{}: column not found in parquet file",
+                    field.name()
+                ))
+            })?
+            .data_type();
+
+        match (&expected, actual) {
+            (
+                ArrowDataType::FixedSizeList(_, expected_dim),
+                ArrowDataType::FixedSizeList(_, actual_dim),
+            ) if expected_dim != actual_dim => {
+                return Err(Error::DimensionMismatch {
+                    expected: *expected_dim as usize,
+                    actual: *actual_dim as usize,
+                })
+            }
+            (expected, actual)
+                if std::mem::discriminant(expected) != std::mem::discriminant(actual) =>
+            {
+                return Err(Error::InvalidArgument(format!(
+                    "{}: expected {expected:?}, found {actual:?}",
+                    field.name()
+                )))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn set_doc_field_from_column(
+    doc: &mut Doc,
+    field_name: &str,
+    column: &dyn Array,
+    row: usize,
+) -> Result<()> {
+    match column.data_type() {
+        ArrowDataType::Utf8 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(|| Error::InvalidArgument(format!("{field_name}: expected Utf8")))?;
+            doc.set_string(field_name, array.value(row))
+        }
+        ArrowDataType::Int64 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .ok_or_else(|| Error::InvalidArgument(format!("{field_name}: expected Int64")))?;
+            doc.set_int64(field_name, array.value(row))
+        }
+        ArrowDataType::Float64 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or_else(|| Error::InvalidArgument(format!("{field_name}: expected Float64")))?;
+            doc.set_double(field_name, array.value(row))
+        }
+        ArrowDataType::FixedSizeList(_, dimension) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected FixedSizeList"))
+                })?;
+            let values = array.value(row);
+            let floats = values
+                .as_any()
+                .downcast_ref::<Float32Array>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected FixedSizeList<Float32>"))
+                })?;
+            if floats.len() != *dimension as usize {
+                return Err(Error::DimensionMismatch {
+                    expected: *dimension as usize,
+                    actual: floats.len(),
+                });
+            }
+            doc.set_vector(field_name, floats.values())
+        }
+        ArrowDataType::List(_) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<ListArray>()
+                .ok_or_else(|| Error::InvalidArgument(format!("{field_name}: expected List")))?;
+            let values = array.value(row);
+            let floats = values
+                .as_any()
+                .downcast_ref::<Float32Array>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected List<Float32>"))
+                })?;
+            doc.set_vector(field_name, floats.values())
+        }
+        ArrowDataType::Struct(_) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .ok_or_else(|| Error::InvalidArgument(format!("{field_name}: expected Struct")))?;
+
+            let indices_col = array.column_by_name("indices").ok_or_else(|| {
+                Error::InvalidArgument(format!("{field_name}: missing 'indices' column"))
+            })?;
+            let values_col = array.column_by_name("values").ok_or_else(|| {
+                Error::InvalidArgument(format!("{field_name}: missing 'values' column"))
+            })?;
+
+            let indices_list = indices_col
+                .as_any()
+                .downcast_ref::<ListArray>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected indices: List<Int32>"))
+                })?;
+            let values_list = values_col
+                .as_any()
+                .downcast_ref::<ListArray>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected values: List<Float32>"))
+                })?;
+
+            let index_values = indices_list.value(row);
+            let indices = index_values
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected indices: List<Int32>"))
+                })?;
+            let value_values = values_list.value(row);
+            let values = value_values
+                .as_any()
+                .downcast_ref::<Float32Array>()
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("{field_name}: expected values: List<Float32>"))
+                })?;
+
+            let indices: Vec<u32> = indices.values().iter().map(|&i| i as u32).collect();
+            doc.set_sparse_vector(field_name, &indices, values.values())
+        }
+        other => Err(Error::NotSupported(format!(
+            "{field_name}: unsupported arrow column type {other:?}"
+        ))),
+    }
+}
+
+/// Map a [`DataType`] to the canonical Arrow type it round-trips through.
+///
+/// Returns `None` for types that have no direct Arrow representation yet.
+pub fn arrow_type_for(data_type: DataType, dimension: u32) -> Option<ArrowDataType> {
+    match data_type {
+        DataType::Bool => Some(ArrowDataType::Boolean),
+        DataType::Int32 => Some(ArrowDataType::Int32),
+        DataType::String => Some(ArrowDataType::Utf8),
+        DataType::Int64 => Some(ArrowDataType::Int64),
+        DataType::Float => Some(ArrowDataType::Float32),
+        DataType::Double => Some(ArrowDataType::Float64),
+        DataType::VectorFp32 | DataType::VectorFp16 => Some(ArrowDataType::FixedSizeList(
+            std::sync::Arc::new(arrow::datatypes::Field::new(
+                "item",
+                ArrowDataType::Float32,
+                false,
+            )),
+            dimension as i32,
+        )),
+        DataType::SparseVectorFp32 => Some(ArrowDataType::Struct(
+            vec![
+                arrow::datatypes::Field::new(
+                    "indices",
+                    ArrowDataType::List(std::sync::Arc::new(arrow::datatypes::Field::new(
+                        "item",
+                        ArrowDataType::Int32,
+                        false,
+                    ))),
+                    false,
+                ),
+                arrow::datatypes::Field::new(
+                    "values",
+                    ArrowDataType::List(std::sync::Arc::new(arrow::datatypes::Field::new(
+                        "item",
+                        ArrowDataType::Float32,
+                        false,
+                    ))),
+                    false,
+                ),
+            ]
+            .into(),
+        )),
+        _ => None,
+    }
+}
+
+/// Inverse of [`arrow_type_for`]: map an Arrow type back to the
+/// `(DataType, dimension)` it round-trips through. A `FixedSizeList<Float32>`
+/// always comes back as `VectorFp32`, since that's the one Arrow shape both
+/// `VectorFp32` and `VectorFp16` export to.
+fn data_type_for_arrow(arrow_type: &ArrowDataType) -> Option<(DataType, u32)> {
+    match arrow_type {
+        ArrowDataType::Boolean => Some((DataType::Bool, 0)),
+        ArrowDataType::Int32 => Some((DataType::Int32, 0)),
+        ArrowDataType::Int64 => Some((DataType::Int64, 0)),
+        ArrowDataType::Float32 => Some((DataType::Float, 0)),
+        ArrowDataType::Float64 => Some((DataType::Double, 0)),
+        ArrowDataType::Utf8 => Some((DataType::String, 0)),
+        ArrowDataType::FixedSizeList(child, dimension)
+            if child.data_type() == &ArrowDataType::Float32 =>
+        {
+            Some((DataType::VectorFp32, *dimension as u32))
+        }
+        ArrowDataType::Struct(fields)
+            if fields.len() == 2
+                && fields[0].name() == "indices"
+                && fields[1].name() == "values" =>
+        {
+            Some((DataType::SparseVectorFp32, 0))
+        }
+        _ => None,
+    }
+}
+
+impl FieldSchema {
+    /// Export this field as a standalone Arrow C Data Interface schema node
+    /// (`FFI_ArrowSchema`), so it can cross an FFI boundary into arrow-rs or
+    /// pyarrow. See [`CollectionSchema::export_arrow`] to export a whole
+    /// schema at once.
+    pub fn export_arrow(&self) -> Result<FFI_ArrowSchema> {
+        let arrow_type = arrow_type_for(self.data_type(), self.dimension()).ok_or_else(|| {
+            Error::NotSupported(format!(
+                "{}: {:?} has no Arrow column mapping",
+                self.name(),
+                self.data_type()
+            ))
+        })?;
+        let field = ArrowField::new(self.name(), arrow_type, self.nullable());
+        FFI_ArrowSchema::try_from(&field)
+            .map_err(|e| Error::InternalError(format!("failed to export arrow schema: {e}")))
+    }
+}
+
+impl CollectionSchema {
+    /// Export this schema as an Arrow C Data Interface struct schema node
+    /// (`FFI_ArrowSchema`) whose children are each field, exported the same
+    /// way as [`FieldSchema::export_arrow`]. See [`import_arrow`] for the
+    /// inverse operation.
+    pub fn export_arrow(&self) -> Result<FFI_ArrowSchema> {
+        let mut children = Vec::with_capacity(self.field_count());
+        for field in self.fields() {
+            let arrow_type =
+                arrow_type_for(field.data_type(), field.dimension()).ok_or_else(|| {
+                    Error::NotSupported(format!(
+                        "{}: {:?} has no Arrow column mapping",
+                        field.name(),
+                        field.data_type()
+                    ))
+                })?;
+            children.push(ArrowField::new(field.name(), arrow_type, field.nullable()));
+        }
+        let struct_field =
+            ArrowField::new(self.name(), ArrowDataType::Struct(children.into()), false);
+        FFI_ArrowSchema::try_from(&struct_field)
+            .map_err(|e| Error::InternalError(format!("failed to export arrow schema: {e}")))
+    }
+}
+
+/// Reconstruct a [`CollectionSchema`] from a borrowed Arrow C Data Interface
+/// struct schema node previously produced by
+/// [`CollectionSchema::export_arrow`].
+pub fn import_arrow(ffi_schema: &FFI_ArrowSchema) -> Result<CollectionSchema> {
+    let struct_field = ArrowField::try_from(ffi_schema)
+        .map_err(|e| Error::InternalError(format!("failed to import arrow schema: {e}")))?;
+    let children = match struct_field.data_type() {
+        ArrowDataType::Struct(children) => children,
+        other => {
+            return Err(Error::InvalidArgument(format!(
+                "expected a Struct schema node, found {other:?}"
+            )))
+        }
+    };
+
+    let mut schema = CollectionSchema::new(struct_field.name());
+    for child in children {
+        let (data_type, dimension) = data_type_for_arrow(child.data_type()).ok_or_else(|| {
+            Error::NotSupported(format!(
+                "{}: {:?} has no field mapping",
+                child.name(),
+                child.data_type()
+            ))
+        })?;
+        let mut field_schema = if dimension > 0 {
+            FieldSchema::new_vector(child.name(), data_type, dimension)
+        } else {
+            FieldSchema::new(child.name(), data_type)
+        };
+        field_schema.set_nullable(child.is_nullable());
+        schema.add_field(field_schema)?;
+    }
+    Ok(schema)
+}
@@ -0,0 +1,160 @@
+//! Async client surface mirroring the blocking [`Collection`] API.
+//!
+//! Gate this module behind the `async` cargo feature.
+
+use std::sync::Arc;
+
+use tokio::task::spawn_blocking;
+
+use crate::collection::{Collection, IndexParams};
+use crate::doc::{Doc, DocList, DocMap, WriteResults};
+use crate::error::{Error, Result};
+use crate::query::{GroupByVectorQuery, GroupResults, VectorQuery};
+
+/// The blocking operations [`Collection`] already exposes as inherent
+/// methods, named as a trait so generic code can be written against "a
+/// collection that supports blocking writes/reads" without committing to a
+/// concrete wrapper.
+///
+/// Retry-and-confirm semantics are unchanged from calling the inherent
+/// methods directly; this trait only adds a name for them.
+pub trait SyncCollection {
+    fn insert(&self, docs: &[Doc]) -> Result<WriteResults>;
+    fn upsert(&self, docs: &[Doc]) -> Result<WriteResults>;
+    fn update(&self, docs: &[Doc]) -> Result<WriteResults>;
+    fn delete(&self, pks: &[&str]) -> Result<WriteResults>;
+    fn fetch(&self, pks: &[&str]) -> Result<DocMap>;
+    fn flush(&self) -> Result<()>;
+}
+
+impl SyncCollection for Collection {
+    fn insert(&self, docs: &[Doc]) -> Result<WriteResults> {
+        Collection::insert(self, docs)
+    }
+
+    fn upsert(&self, docs: &[Doc]) -> Result<WriteResults> {
+        Collection::upsert(self, docs)
+    }
+
+    fn update(&self, docs: &[Doc]) -> Result<WriteResults> {
+        Collection::update(self, docs)
+    }
+
+    fn delete(&self, pks: &[&str]) -> Result<WriteResults> {
+        Collection::delete(self, pks)
+    }
+
+    fn fetch(&self, pks: &[&str]) -> Result<DocMap> {
+        Collection::fetch(self, pks)
+    }
+
+    fn flush(&self) -> Result<()> {
+        Collection::flush(self)
+    }
+}
+
+/// Non-blocking counterparts of [`SyncCollection`], run on the Tokio
+/// blocking threadpool via `spawn_blocking` so a server embedding zvec can
+/// drive thousands of concurrent fetches without dedicating one OS thread
+/// per request.
+///
+/// Implemented for `Arc<Collection>` rather than `Collection` directly: a
+/// blocking task must hold a `'static`, independently-droppable handle to
+/// the collection rather than borrowing `&self` across an await point, and
+/// `Arc` is the cheap-clone handle this crate already uses for sharing a
+/// collection across threads (see [`crate::sync::SharedCollection`]).
+#[allow(async_fn_in_trait)]
+pub trait AsyncCollection {
+    /// Execute a vector similarity search query.
+    async fn query(&self, query: VectorQuery) -> Result<DocList>;
+    /// Execute a grouped vector similarity search query.
+    async fn group_by_query(&self, query: GroupByVectorQuery) -> Result<GroupResults>;
+    async fn insert(&self, docs: Vec<Doc>) -> Result<WriteResults>;
+    async fn upsert(&self, docs: Vec<Doc>) -> Result<WriteResults>;
+    async fn update(&self, docs: Vec<Doc>) -> Result<WriteResults>;
+    async fn delete(&self, pks: Vec<String>) -> Result<WriteResults>;
+    async fn fetch(&self, pks: Vec<String>) -> Result<DocMap>;
+
+    /// Build (or rebuild) an index on `column_name`.
+    async fn create_index(&self, column_name: String, params: IndexParams) -> Result<()>;
+
+    /// Optimize the collection for better search performance.
+    async fn optimize(&self) -> Result<()>;
+
+    /// Flush pending writes and resolve once they are durable.
+    async fn flush(&self) -> Result<()>;
+}
+
+impl AsyncCollection for Arc<Collection> {
+    async fn query(&self, query: VectorQuery) -> Result<DocList> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.query(query))).await
+    }
+
+    async fn group_by_query(&self, query: GroupByVectorQuery) -> Result<GroupResults> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.group_by_query(query))).await
+    }
+
+    async fn insert(&self, docs: Vec<Doc>) -> Result<WriteResults> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.insert(&docs))).await
+    }
+
+    async fn upsert(&self, docs: Vec<Doc>) -> Result<WriteResults> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.upsert(&docs))).await
+    }
+
+    async fn update(&self, docs: Vec<Doc>) -> Result<WriteResults> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.update(&docs))).await
+    }
+
+    async fn delete(&self, pks: Vec<String>) -> Result<WriteResults> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || {
+            let pk_refs: Vec<&str> = pks.iter().map(String::as_str).collect();
+            collection.delete(&pk_refs)
+        }))
+        .await
+    }
+
+    async fn fetch(&self, pks: Vec<String>) -> Result<DocMap> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || {
+            let pk_refs: Vec<&str> = pks.iter().map(String::as_str).collect();
+            collection.fetch(&pk_refs)
+        }))
+        .await
+    }
+
+    async fn create_index(&self, column_name: String, params: IndexParams) -> Result<()> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || {
+            collection.create_index(&column_name, params)
+        }))
+        .await
+    }
+
+    async fn optimize(&self) -> Result<()> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.optimize())).await
+    }
+
+    async fn flush(&self) -> Result<()> {
+        let collection = Arc::clone(self);
+        join_blocking(spawn_blocking(move || collection.flush())).await
+    }
+}
+
+/// Await a `spawn_blocking` handle, turning a panicked/cancelled task into
+/// an [`Error::InternalError`] instead of propagating a `JoinError`.
+async fn join_blocking<T>(handle: tokio::task::JoinHandle<Result<T>>) -> Result<T> {
+    match handle.await {
+        Ok(result) => result,
+        Err(e) => Err(Error::InternalError(format!(
+            "async collection task failed: {e}"
+        ))),
+    }
+}
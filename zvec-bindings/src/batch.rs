@@ -0,0 +1,197 @@
+//! Multi-operation write batch.
+//!
+//! [`Collection`] exposes `insert`/`upsert`/`update`/`delete`/
+//! `delete_by_filter` as independent calls, each of which commits to zvec
+//! immediately. [`WriteBatch`] lets a caller queue a mixed sequence of them
+//! and submit it with one [`WriteBatch::commit`] call, preserving call
+//! order and aggregating every operation's per-document results into one
+//! [`BatchResults`].
+//!
+//! zvec has no multi-statement transaction primitive to build true
+//! atomicity on, so [`WriteBatch::commit`] stops at the first operation
+//! that returns an error and propagates it, but it does **not** roll back
+//! operations that already applied earlier in the batch. Callers relying
+//! on an all-or-nothing outcome should order the riskiest operation last
+//! (e.g. delete-then-reinsert for a re-embedding pass, with the delete
+//! first) rather than assume a failed commit leaves the collection
+//! untouched.
+
+use crate::collection::Collection;
+use crate::doc::{Doc, WriteResults};
+use crate::error::Result;
+
+enum WriteOp {
+    Insert(Vec<Doc>),
+    Upsert(Vec<Doc>),
+    Update(Vec<Doc>),
+    Delete(Vec<String>),
+    DeleteByFilter(String),
+}
+
+/// Options controlling [`Collection::bulk_insert`]'s chunking, periodic
+/// flush, and per-document retry behavior.
+#[derive(Debug, Clone, Copy)]
+pub struct BulkOptions {
+    /// Number of documents passed to each underlying `insert` call.
+    pub chunk_size: usize,
+    /// Call [`Collection::flush`] after every `n` chunks, to bound how
+    /// much unflushed data the collection holds in memory. `None` never
+    /// flushes early.
+    pub flush_every_n_chunks: Option<usize>,
+    /// Maximum retry attempts for a document whose insert came back with
+    /// an [`crate::error::Error::is_transient`] failure. Non-transient
+    /// failures (e.g. a malformed document) are never retried.
+    pub max_retries: u32,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1000,
+            flush_every_n_chunks: None,
+            max_retries: 3,
+        }
+    }
+}
+
+/// Builder collecting an ordered sequence of write operations to submit
+/// together via [`Collection::batch`] / [`Self::commit`].
+pub struct WriteBatch<'a> {
+    collection: &'a Collection,
+    ops: Vec<WriteOp>,
+}
+
+impl<'a> WriteBatch<'a> {
+    pub(crate) fn new(collection: &'a Collection) -> Self {
+        Self {
+            collection,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Queue an [`Collection::insert`] of `docs`.
+    pub fn insert(mut self, docs: Vec<Doc>) -> Self {
+        self.ops.push(WriteOp::Insert(docs));
+        self
+    }
+
+    /// Queue a [`Collection::upsert`] of `docs`.
+    pub fn upsert(mut self, docs: Vec<Doc>) -> Self {
+        self.ops.push(WriteOp::Upsert(docs));
+        self
+    }
+
+    /// Queue a [`Collection::update`] of `docs`.
+    pub fn update(mut self, docs: Vec<Doc>) -> Self {
+        self.ops.push(WriteOp::Update(docs));
+        self
+    }
+
+    /// Queue a [`Collection::delete`] of `pks`.
+    pub fn delete(mut self, pks: &[&str]) -> Self {
+        self.ops.push(WriteOp::Delete(
+            pks.iter().map(|pk| pk.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// Queue a [`Collection::delete_by_filter`] for `filter`.
+    pub fn delete_by_filter(mut self, filter: &str) -> Self {
+        self.ops.push(WriteOp::DeleteByFilter(filter.to_string()));
+        self
+    }
+
+    /// Submit all queued operations in order, aggregating their results
+    /// into one [`BatchResults`].
+    ///
+    /// Stops and returns the error from the first operation that fails;
+    /// see the module docs for why operations applied before it are not
+    /// rolled back.
+    pub fn commit(self) -> Result<BatchResults> {
+        let mut entries = Vec::with_capacity(self.ops.len());
+        for op in self.ops {
+            let entry = match op {
+                WriteOp::Insert(docs) => BatchEntry::Write(self.collection.insert(&docs)?),
+                WriteOp::Upsert(docs) => BatchEntry::Write(self.collection.upsert(&docs)?),
+                WriteOp::Update(docs) => BatchEntry::Write(self.collection.update(&docs)?),
+                WriteOp::Delete(pks) => {
+                    let pk_refs: Vec<&str> = pks.iter().map(String::as_str).collect();
+                    BatchEntry::Write(self.collection.delete(&pk_refs)?)
+                }
+                WriteOp::DeleteByFilter(filter) => {
+                    self.collection.delete_by_filter(&filter)?;
+                    BatchEntry::Filtered
+                }
+            };
+            entries.push(entry);
+        }
+        Ok(BatchResults { entries })
+    }
+}
+
+enum BatchEntry {
+    Write(WriteResults),
+    Filtered,
+    /// Per-document results assembled in Rust rather than held in one
+    /// native [`WriteResults`] - see [`Collection::bulk_insert`], whose
+    /// chunked retries can overwrite individual documents' outcomes after
+    /// the chunk's own native result was already read.
+    Materialized(Vec<Result<()>>),
+}
+
+impl BatchResults {
+    /// Wrap per-document results assembled across several native calls
+    /// (e.g. [`Collection::bulk_insert`]'s chunk-then-retry loop) as one
+    /// [`BatchResults`], for callers that already have owned `Result<()>`
+    /// values rather than a single native [`WriteResults`].
+    pub(crate) fn from_materialized(results: Vec<Result<()>>) -> Self {
+        Self {
+            entries: vec![BatchEntry::Materialized(results)],
+        }
+    }
+}
+
+/// Aggregated per-document results from [`WriteBatch::commit`], in queue
+/// order across every operation.
+///
+/// [`WriteBatch::delete_by_filter`] doesn't report a native per-document
+/// count, so it contributes no entries here even though it still runs as
+/// part of the batch.
+pub struct BatchResults {
+    entries: Vec<BatchEntry>,
+}
+
+impl BatchResults {
+    /// Total number of per-document results across all queued operations.
+    pub fn len(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| match entry {
+                BatchEntry::Write(results) => results.len(),
+                BatchEntry::Filtered => 0,
+                BatchEntry::Materialized(results) => results.len(),
+            })
+            .sum()
+    }
+
+    /// Whether no operation in the batch produced a per-document result.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Per-document results across all queued operations, in the order the
+    /// operations were queued and the documents were passed to each.
+    pub fn iter(&self) -> impl Iterator<Item = Result<()>> + '_ {
+        self.entries.iter().flat_map(|entry| match entry {
+            BatchEntry::Write(results) => {
+                Box::new(results.iter()) as Box<dyn Iterator<Item = Result<()>>>
+            }
+            BatchEntry::Filtered => {
+                Box::new(std::iter::empty()) as Box<dyn Iterator<Item = Result<()>>>
+            }
+            BatchEntry::Materialized(results) => {
+                Box::new(results.iter().cloned()) as Box<dyn Iterator<Item = Result<()>>>
+            }
+        })
+    }
+}
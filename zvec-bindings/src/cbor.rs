@@ -0,0 +1,839 @@
+//! Self-describing CBOR encoding for [`Doc`] and [`CollectionSchema`].
+//!
+//! Each encoded value is tagged with its `DataType` name so the bytes can be
+//! moved across processes (replication, message-queue ingestion, saving a
+//! batch to a file) and read back without a running collection to ask.
+//!
+//! [`Doc::to_cbor`] needs `schema` to know which fields to probe for, since
+//! the FFI has no "list the fields actually set on this doc" call; decoding
+//! with [`Doc::from_cbor`] doesn't, because the tags carry that back out.
+//! `SparseVectorFp32` fields have no CBOR encoding: `to_cbor` returns
+//! [`Error::NotSupported`] for a doc that has one set rather than silently
+//! dropping it, and [`Doc::from_cbor`] has no decoder for the
+//! `SparseVectorFp32` tag. Likewise a field explicitly set to null round-trips through
+//! the CBOR bytes as `Value::Null`, but there is no `zvec_doc_set_null` hook
+//! to write that back onto a decoded `Doc`, so `from_cbor` errors on it
+//! instead of silently decoding to "absent".
+//!
+//! Also backs [`crate::collection::Collection::export_snapshot`] /
+//! `import_snapshot`'s portable snapshot container, which wraps an encoded
+//! [`CollectionSchema`], a list of encoded `Doc`s, and each indexed field's
+//! [`IndexSpec`] construction arguments behind a version header.
+//!
+//! Gate this module behind the `cbor` cargo feature.
+
+use std::collections::HashMap;
+
+use ciborium::value::Value;
+
+use crate::collection::IndexSpec;
+use crate::doc::{Doc, DocRef};
+use crate::error::{Error, Result};
+use crate::schema::{CollectionSchema, FieldSchema};
+use crate::types::{DataType, MetricType, QuantizeType};
+
+impl Doc {
+    /// Encode this document as a self-describing CBOR map, probing each
+    /// field named in `schema` in turn.
+    ///
+    /// Fields never set on this doc are omitted entirely; fields set to
+    /// null are encoded as CBOR null; fields with a value are encoded as a
+    /// `{"type": ..., "value": ...}` map tagged with their `DataType`.
+    pub fn to_cbor(&self, schema: &CollectionSchema) -> Result<Vec<u8>> {
+        let mut entries = Vec::new();
+        for field in schema.fields() {
+            let name = field.name();
+            if !self.has(name) {
+                continue;
+            }
+            if self.is_null(name) {
+                entries.push((Value::Text(name.to_string()), Value::Null));
+                continue;
+            }
+            let value = self.field_to_cbor_value(&field)?;
+            entries.push((Value::Text(name.to_string()), value));
+        }
+
+        let doc = Value::Map(vec![
+            (Value::Text("pk".into()), Value::Text(self.pk().to_string())),
+            (Value::Text("fields".into()), Value::Map(entries)),
+        ]);
+
+        let mut buf = Vec::new();
+        ciborium::ser::into_writer(&doc, &mut buf)
+            .map_err(|e| Error::InternalError(format!("failed to encode doc as cbor: {e}")))?;
+        Ok(buf)
+    }
+
+    fn field_to_cbor_value(&self, field: &FieldSchema) -> Result<Value> {
+        let name = field.name();
+        let data_type = field.data_type();
+        let value = match data_type {
+            DataType::Bool => self.get_bool(name).map(Value::Bool),
+            DataType::Int32 => self.get_int32(name).map(|v| Value::Integer(v.into())),
+            DataType::Int64 => self.get_int64(name).map(|v| Value::Integer(v.into())),
+            DataType::Float => self.get_float(name).map(|v| Value::Float(v as f64)),
+            DataType::Double => self.get_double(name).map(Value::Float),
+            DataType::String => self.get_string(name).map(|v| Value::Text(v.to_string())),
+            DataType::VectorFp32 => self
+                .get_vector(name)
+                .map(|v| Value::Array(v.into_iter().map(|f| Value::Float(f as f64)).collect())),
+            DataType::SparseVectorFp32 => {
+                return Err(Error::NotSupported(format!(
+                    "{name}: SparseVectorFp32 fields have no CBOR encoding"
+                )));
+            }
+            other => {
+                return Err(Error::NotSupported(format!(
+                    "{name}: {other:?} has no CBOR encoding yet"
+                )));
+            }
+        };
+        let value = value.ok_or_else(|| {
+            Error::InternalError(format!(
+                "{name}: has() reported a value but the getter returned none"
+            ))
+        })?;
+        Ok(Value::Map(vec![
+            (
+                Value::Text("type".into()),
+                Value::Text(data_type_tag(data_type).to_string()),
+            ),
+            (Value::Text("value".into()), value),
+        ]))
+    }
+
+    /// Decode a document previously produced by [`Self::to_cbor`].
+    ///
+    /// Fields tagged with a `DataType` this build can write back (bool,
+    /// int32/64, float/double, string, dense fp32 vectors) are restored via
+    /// the matching `set_*` call; an explicit CBOR null, or a tag this build
+    /// can't write, is reported as [`Error::NotSupported`] rather than
+    /// silently dropped.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Doc> {
+        let value: Value = ciborium::de::from_reader(bytes)
+            .map_err(|e| Error::InvalidArgument(format!("failed to decode cbor doc: {e}")))?;
+        let map = value
+            .into_map()
+            .map_err(|_| Error::InvalidArgument("cbor doc must be a map".into()))?;
+
+        let mut pk = None;
+        let mut fields = None;
+        for (key, val) in map {
+            match key.as_text() {
+                Some("pk") => pk = val.into_text().ok(),
+                Some("fields") => fields = val.into_map().ok(),
+                _ => {}
+            }
+        }
+
+        let mut doc = match pk {
+            Some(pk) => Doc::with_pk(pk),
+            None => Doc::new(),
+        };
+
+        for (key, val) in fields.unwrap_or_default() {
+            let name = key
+                .into_text()
+                .map_err(|_| Error::InvalidArgument("cbor field key must be a string".into()))?;
+            if val.is_null() {
+                return Err(Error::NotSupported(format!(
+                    "{name}: can't decode an explicit null field, no zvec_doc_set_null hook in this build"
+                )));
+            }
+            set_field_from_cbor_value(&mut doc, &name, val)?;
+        }
+
+        Ok(doc)
+    }
+}
+
+impl<'a> DocRef<'a> {
+    /// Encode this document as self-describing CBOR, like [`Doc::to_cbor`].
+    ///
+    /// Used by [`crate::collection::Collection::export_snapshot`], which
+    /// only has documents read back via
+    /// [`crate::collection::Collection::fetch`] (a [`DocRef`], not an owned
+    /// [`Doc`]) to encode.
+    pub fn to_cbor(&self, schema: &CollectionSchema) -> Result<Vec<u8>> {
+        let mut entries = Vec::new();
+        for field in schema.fields() {
+            let name = field.name();
+            if !self.has(name) {
+                continue;
+            }
+            if self.is_null(name) {
+                entries.push((Value::Text(name.to_string()), Value::Null));
+                continue;
+            }
+            let value = self.field_to_cbor_value(&field)?;
+            entries.push((Value::Text(name.to_string()), value));
+        }
+
+        let doc = Value::Map(vec![
+            (Value::Text("pk".into()), Value::Text(self.pk().to_string())),
+            (Value::Text("fields".into()), Value::Map(entries)),
+        ]);
+
+        let mut buf = Vec::new();
+        ciborium::ser::into_writer(&doc, &mut buf)
+            .map_err(|e| Error::InternalError(format!("failed to encode doc as cbor: {e}")))?;
+        Ok(buf)
+    }
+
+    fn field_to_cbor_value(&self, field: &FieldSchema) -> Result<Value> {
+        let name = field.name();
+        let data_type = field.data_type();
+        let value = match data_type {
+            DataType::Bool => self.get_bool(name).map(Value::Bool),
+            DataType::Int32 => self.get_int32(name).map(|v| Value::Integer(v.into())),
+            DataType::Int64 => self.get_int64(name).map(|v| Value::Integer(v.into())),
+            DataType::Float => self.get_float(name).map(|v| Value::Float(v as f64)),
+            DataType::Double => self.get_double(name).map(Value::Float),
+            DataType::String => self.get_string(name).map(|v| Value::Text(v.to_string())),
+            DataType::VectorFp32 => self
+                .get_vector(name)
+                .map(|v| Value::Array(v.into_iter().map(|f| Value::Float(f as f64)).collect())),
+            DataType::SparseVectorFp32 => {
+                return Err(Error::NotSupported(format!(
+                    "{name}: SparseVectorFp32 fields have no CBOR encoding"
+                )));
+            }
+            other => {
+                return Err(Error::NotSupported(format!(
+                    "{name}: {other:?} has no CBOR encoding yet"
+                )));
+            }
+        };
+        let value = value.ok_or_else(|| {
+            Error::InternalError(format!(
+                "{name}: has() reported a value but the getter returned none"
+            ))
+        })?;
+        Ok(Value::Map(vec![
+            (
+                Value::Text("type".into()),
+                Value::Text(data_type_tag(data_type).to_string()),
+            ),
+            (Value::Text("value".into()), value),
+        ]))
+    }
+}
+
+fn set_field_from_cbor_value(doc: &mut Doc, name: &str, value: Value) -> Result<()> {
+    let map = value.into_map().map_err(|_| {
+        Error::InvalidArgument(format!("{name}: expected a tagged {{type, value}} map"))
+    })?;
+
+    let mut tag = None;
+    let mut inner = None;
+    for (key, val) in map {
+        match key.as_text() {
+            Some("type") => tag = val.into_text().ok(),
+            Some("value") => inner = Some(val),
+            _ => {}
+        }
+    }
+    let tag = tag.ok_or_else(|| Error::InvalidArgument(format!("{name}: missing 'type' tag")))?;
+    let inner = inner.ok_or_else(|| Error::InvalidArgument(format!("{name}: missing 'value'")))?;
+
+    match tag.as_str() {
+        "Bool" => doc.set_bool(name, inner.as_bool().ok_or_else(|| bad_value(name))?),
+        "Int32" => doc.set_int32(
+            name,
+            inner
+                .as_integer()
+                .and_then(|i| i32::try_from(i).ok())
+                .ok_or_else(|| bad_value(name))?,
+        ),
+        "Int64" => doc.set_int64(
+            name,
+            inner
+                .as_integer()
+                .and_then(|i| i64::try_from(i).ok())
+                .ok_or_else(|| bad_value(name))?,
+        ),
+        "Float" => doc.set_float(
+            name,
+            inner.as_float().ok_or_else(|| bad_value(name))? as f32,
+        ),
+        "Double" => doc.set_double(name, inner.as_float().ok_or_else(|| bad_value(name))?),
+        "String" => doc.set_string(name, inner.as_text().ok_or_else(|| bad_value(name))?),
+        "VectorFp32" => {
+            let array = inner.as_array().ok_or_else(|| bad_value(name))?;
+            let floats: Vec<f32> = array
+                .iter()
+                .map(|v| {
+                    v.as_float()
+                        .map(|f| f as f32)
+                        .ok_or_else(|| bad_value(name))
+                })
+                .collect::<Result<_>>()?;
+            doc.set_vector(name, &floats)
+        }
+        other => Err(Error::NotSupported(format!(
+            "{name}: {other} has no CBOR decoder in this build"
+        ))),
+    }
+}
+
+fn bad_value(field: &str) -> Error {
+    Error::InvalidArgument(format!("{field}: value doesn't match its CBOR type tag"))
+}
+
+impl CollectionSchema {
+    /// Encode this schema's fields (and any
+    /// [`register_embedder`](Self::register_embedder) mappings) as
+    /// self-describing CBOR.
+    pub fn to_cbor(&self) -> Result<Vec<u8>> {
+        let fields = self
+            .fields()
+            .iter()
+            .map(|field| {
+                Value::Map(vec![
+                    (
+                        Value::Text("name".into()),
+                        Value::Text(field.name().to_string()),
+                    ),
+                    (
+                        Value::Text("data_type".into()),
+                        Value::Text(data_type_tag(field.data_type()).to_string()),
+                    ),
+                    (
+                        Value::Text("dimension".into()),
+                        Value::Integer(field.dimension().into()),
+                    ),
+                    (
+                        Value::Text("nullable".into()),
+                        Value::Bool(field.nullable()),
+                    ),
+                    (
+                        Value::Text("dictionary_encoded".into()),
+                        Value::Bool(field.is_dictionary_encoded()),
+                    ),
+                ])
+            })
+            .collect();
+
+        let embedder_mappings = self
+            .embedder_mappings()
+            .iter()
+            .map(|mapping| {
+                Value::Map(vec![
+                    (
+                        Value::Text("source_field".into()),
+                        Value::Text(mapping.source_field.clone()),
+                    ),
+                    (
+                        Value::Text("target_field".into()),
+                        Value::Text(mapping.target_field.clone()),
+                    ),
+                    (
+                        Value::Text("target_dimension".into()),
+                        Value::Integer(mapping.target_dimension.into()),
+                    ),
+                ])
+            })
+            .collect();
+
+        let schema = Value::Map(vec![
+            (
+                Value::Text("name".into()),
+                Value::Text(self.name().to_string()),
+            ),
+            (Value::Text("fields".into()), Value::Array(fields)),
+            (
+                Value::Text("embedder_mappings".into()),
+                Value::Array(embedder_mappings),
+            ),
+        ]);
+
+        let mut buf = Vec::new();
+        ciborium::ser::into_writer(&schema, &mut buf)
+            .map_err(|e| Error::InternalError(format!("failed to encode schema as cbor: {e}")))?;
+        Ok(buf)
+    }
+
+    /// Decode a schema previously produced by [`Self::to_cbor`].
+    pub fn from_cbor(bytes: &[u8]) -> Result<CollectionSchema> {
+        let value: Value = ciborium::de::from_reader(bytes)
+            .map_err(|e| Error::InvalidArgument(format!("failed to decode cbor schema: {e}")))?;
+        let map = value
+            .into_map()
+            .map_err(|_| Error::InvalidArgument("cbor schema must be a map".into()))?;
+
+        let mut name = None;
+        let mut fields = None;
+        let mut embedder_mappings = Vec::new();
+        for (key, val) in map {
+            match key.as_text() {
+                Some("name") => name = val.into_text().ok(),
+                Some("fields") => fields = val.into_array().ok(),
+                Some("embedder_mappings") => {
+                    embedder_mappings = val.into_array().unwrap_or_default();
+                }
+                _ => {}
+            }
+        }
+
+        let name =
+            name.ok_or_else(|| Error::InvalidArgument("cbor schema missing 'name'".into()))?;
+        let mut schema = CollectionSchema::new(&name);
+
+        for field_value in fields.unwrap_or_default() {
+            let field_map = field_value
+                .into_map()
+                .map_err(|_| Error::InvalidArgument("cbor schema field must be a map".into()))?;
+
+            let mut field_name = None;
+            let mut data_type_str = None;
+            let mut dimension = 0u32;
+            let mut nullable = false;
+            let mut dictionary_encoded = false;
+            for (key, val) in field_map {
+                match key.as_text() {
+                    Some("name") => field_name = val.into_text().ok(),
+                    Some("data_type") => data_type_str = val.into_text().ok(),
+                    Some("dimension") => {
+                        dimension = val
+                            .as_integer()
+                            .and_then(|i| u32::try_from(i).ok())
+                            .unwrap_or(0)
+                    }
+                    Some("nullable") => nullable = val.as_bool().unwrap_or(false),
+                    Some("dictionary_encoded") => {
+                        dictionary_encoded = val.as_bool().unwrap_or(false)
+                    }
+                    _ => {}
+                }
+            }
+
+            let field_name = field_name
+                .ok_or_else(|| Error::InvalidArgument("cbor schema field missing 'name'".into()))?;
+            let data_type_str = data_type_str.ok_or_else(|| {
+                Error::InvalidArgument("cbor schema field missing 'data_type'".into())
+            })?;
+            let data_type = data_type_from_tag(&data_type_str).ok_or_else(|| {
+                Error::InvalidArgument(format!("{field_name}: unknown data_type '{data_type_str}'"))
+            })?;
+
+            let mut field = if dimension > 0 {
+                FieldSchema::new_vector(&field_name, data_type, dimension)
+            } else {
+                FieldSchema::new(&field_name, data_type)
+            }
+            .dictionary_encoded(dictionary_encoded);
+            field.set_nullable(nullable);
+            schema.add_field(field)?;
+        }
+
+        for mapping_value in embedder_mappings {
+            let mapping_map = mapping_value.into_map().map_err(|_| {
+                Error::InvalidArgument("cbor embedder mapping must be a map".into())
+            })?;
+            let mut source_field = None;
+            let mut target_field = None;
+            for (key, val) in mapping_map {
+                match key.as_text() {
+                    Some("source_field") => source_field = val.into_text().ok(),
+                    Some("target_field") => target_field = val.into_text().ok(),
+                    _ => {}
+                }
+            }
+            let source_field = source_field.ok_or_else(|| {
+                Error::InvalidArgument("cbor embedder mapping missing 'source_field'".into())
+            })?;
+            let target_field = target_field.ok_or_else(|| {
+                Error::InvalidArgument("cbor embedder mapping missing 'target_field'".into())
+            })?;
+            schema.register_embedder(&source_field, &target_field)?;
+        }
+
+        Ok(schema)
+    }
+}
+
+/// Stable, human-readable tag for each [`DataType`], used as the CBOR `type`
+/// discriminant so encoded bytes are self-describing.
+fn data_type_tag(data_type: DataType) -> &'static str {
+    match data_type {
+        DataType::Undefined => "Undefined",
+        DataType::Binary => "Binary",
+        DataType::String => "String",
+        DataType::Bool => "Bool",
+        DataType::Int32 => "Int32",
+        DataType::Int64 => "Int64",
+        DataType::UInt32 => "UInt32",
+        DataType::UInt64 => "UInt64",
+        DataType::Float => "Float",
+        DataType::Double => "Double",
+        DataType::VectorBinary32 => "VectorBinary32",
+        DataType::VectorBinary64 => "VectorBinary64",
+        DataType::VectorFp16 => "VectorFp16",
+        DataType::VectorFp32 => "VectorFp32",
+        DataType::VectorFp64 => "VectorFp64",
+        DataType::VectorInt4 => "VectorInt4",
+        DataType::VectorInt8 => "VectorInt8",
+        DataType::VectorInt16 => "VectorInt16",
+        DataType::SparseVectorFp16 => "SparseVectorFp16",
+        DataType::SparseVectorFp32 => "SparseVectorFp32",
+        DataType::ArrayBinary => "ArrayBinary",
+        DataType::ArrayString => "ArrayString",
+        DataType::ArrayBool => "ArrayBool",
+        DataType::ArrayInt32 => "ArrayInt32",
+        DataType::ArrayInt64 => "ArrayInt64",
+        DataType::ArrayUInt32 => "ArrayUInt32",
+        DataType::ArrayUInt64 => "ArrayUInt64",
+        DataType::ArrayFloat => "ArrayFloat",
+        DataType::ArrayDouble => "ArrayDouble",
+    }
+}
+
+fn data_type_from_tag(tag: &str) -> Option<DataType> {
+    Some(match tag {
+        "Undefined" => DataType::Undefined,
+        "Binary" => DataType::Binary,
+        "String" => DataType::String,
+        "Bool" => DataType::Bool,
+        "Int32" => DataType::Int32,
+        "Int64" => DataType::Int64,
+        "UInt32" => DataType::UInt32,
+        "UInt64" => DataType::UInt64,
+        "Float" => DataType::Float,
+        "Double" => DataType::Double,
+        "VectorBinary32" => DataType::VectorBinary32,
+        "VectorBinary64" => DataType::VectorBinary64,
+        "VectorFp16" => DataType::VectorFp16,
+        "VectorFp32" => DataType::VectorFp32,
+        "VectorFp64" => DataType::VectorFp64,
+        "VectorInt4" => DataType::VectorInt4,
+        "VectorInt8" => DataType::VectorInt8,
+        "VectorInt16" => DataType::VectorInt16,
+        "SparseVectorFp16" => DataType::SparseVectorFp16,
+        "SparseVectorFp32" => DataType::SparseVectorFp32,
+        "ArrayBinary" => DataType::ArrayBinary,
+        "ArrayString" => DataType::ArrayString,
+        "ArrayBool" => DataType::ArrayBool,
+        "ArrayInt32" => DataType::ArrayInt32,
+        "ArrayInt64" => DataType::ArrayInt64,
+        "ArrayUInt32" => DataType::ArrayUInt32,
+        "ArrayUInt64" => DataType::ArrayUInt64,
+        "ArrayFloat" => DataType::ArrayFloat,
+        "ArrayDouble" => DataType::ArrayDouble,
+        _ => return None,
+    })
+}
+
+/// Stable, human-readable tag for each [`MetricType`], mirroring
+/// [`data_type_tag`] for [`IndexSpec`] encoding.
+fn metric_tag(metric: MetricType) -> &'static str {
+    match metric {
+        MetricType::Undefined => "Undefined",
+        MetricType::L2 => "L2",
+        MetricType::Ip => "Ip",
+        MetricType::Cosine => "Cosine",
+        MetricType::MipsL2 => "MipsL2",
+    }
+}
+
+fn metric_from_tag(tag: &str) -> Option<MetricType> {
+    Some(match tag {
+        "Undefined" => MetricType::Undefined,
+        "L2" => MetricType::L2,
+        "Ip" => MetricType::Ip,
+        "Cosine" => MetricType::Cosine,
+        "MipsL2" => MetricType::MipsL2,
+        _ => return None,
+    })
+}
+
+/// Stable, human-readable tag for each [`QuantizeType`], mirroring
+/// [`data_type_tag`] for [`IndexSpec`] encoding.
+fn quantize_tag(quantize: QuantizeType) -> &'static str {
+    match quantize {
+        QuantizeType::Undefined => "Undefined",
+        QuantizeType::Fp16 => "Fp16",
+        QuantizeType::Int8 => "Int8",
+        QuantizeType::Int4 => "Int4",
+    }
+}
+
+fn quantize_from_tag(tag: &str) -> Option<QuantizeType> {
+    Some(match tag {
+        "Undefined" => QuantizeType::Undefined,
+        "Fp16" => QuantizeType::Fp16,
+        "Int8" => QuantizeType::Int8,
+        "Int4" => QuantizeType::Int4,
+        _ => return None,
+    })
+}
+
+fn index_spec_to_value(spec: &IndexSpec) -> Value {
+    match *spec {
+        IndexSpec::Hnsw {
+            m,
+            ef_construction,
+            metric,
+            quantize,
+        } => Value::Map(vec![
+            (Value::Text("index_type".into()), Value::Text("Hnsw".into())),
+            (Value::Text("m".into()), Value::Integer(m.into())),
+            (
+                Value::Text("ef_construction".into()),
+                Value::Integer(ef_construction.into()),
+            ),
+            (
+                Value::Text("metric".into()),
+                Value::Text(metric_tag(metric).to_string()),
+            ),
+            (
+                Value::Text("quantize".into()),
+                Value::Text(quantize_tag(quantize).to_string()),
+            ),
+        ]),
+        IndexSpec::Ivf {
+            n_list,
+            n_iters,
+            use_soar,
+            metric,
+            quantize,
+        } => Value::Map(vec![
+            (Value::Text("index_type".into()), Value::Text("Ivf".into())),
+            (Value::Text("n_list".into()), Value::Integer(n_list.into())),
+            (
+                Value::Text("n_iters".into()),
+                Value::Integer(n_iters.into()),
+            ),
+            (Value::Text("use_soar".into()), Value::Bool(use_soar)),
+            (
+                Value::Text("metric".into()),
+                Value::Text(metric_tag(metric).to_string()),
+            ),
+            (
+                Value::Text("quantize".into()),
+                Value::Text(quantize_tag(quantize).to_string()),
+            ),
+        ]),
+        IndexSpec::Flat { metric, quantize } => Value::Map(vec![
+            (Value::Text("index_type".into()), Value::Text("Flat".into())),
+            (
+                Value::Text("metric".into()),
+                Value::Text(metric_tag(metric).to_string()),
+            ),
+            (
+                Value::Text("quantize".into()),
+                Value::Text(quantize_tag(quantize).to_string()),
+            ),
+        ]),
+        IndexSpec::Invert {
+            enable_range_optimization,
+        } => Value::Map(vec![
+            (
+                Value::Text("index_type".into()),
+                Value::Text("Invert".into()),
+            ),
+            (
+                Value::Text("enable_range_optimization".into()),
+                Value::Bool(enable_range_optimization),
+            ),
+        ]),
+    }
+}
+
+fn index_spec_from_value(value: Value) -> Result<IndexSpec> {
+    let map = value
+        .into_map()
+        .map_err(|_| Error::InvalidArgument("index spec must be a cbor map".into()))?;
+    let mut fields: HashMap<String, Value> = HashMap::new();
+    for (key, val) in map {
+        if let Some(key) = key.as_text() {
+            fields.insert(key.to_string(), val);
+        }
+    }
+
+    let index_type = fields
+        .get("index_type")
+        .and_then(|v| v.as_text())
+        .ok_or_else(|| Error::InvalidArgument("index spec missing 'index_type'".into()))?
+        .to_string();
+
+    let get_i32 = |key: &str| -> Result<i32> {
+        fields
+            .get(key)
+            .and_then(|v| v.as_integer())
+            .and_then(|i| i32::try_from(i).ok())
+            .ok_or_else(|| Error::InvalidArgument(format!("index spec missing '{key}'")))
+    };
+    let get_bool = |key: &str| -> Result<bool> {
+        fields
+            .get(key)
+            .and_then(|v| v.as_bool())
+            .ok_or_else(|| Error::InvalidArgument(format!("index spec missing '{key}'")))
+    };
+    let get_metric = || -> Result<MetricType> {
+        fields
+            .get("metric")
+            .and_then(|v| v.as_text())
+            .and_then(metric_from_tag)
+            .ok_or_else(|| Error::InvalidArgument("index spec missing 'metric'".into()))
+    };
+    let get_quantize = || -> Result<QuantizeType> {
+        fields
+            .get("quantize")
+            .and_then(|v| v.as_text())
+            .and_then(quantize_from_tag)
+            .ok_or_else(|| Error::InvalidArgument("index spec missing 'quantize'".into()))
+    };
+
+    Ok(match index_type.as_str() {
+        "Hnsw" => IndexSpec::Hnsw {
+            m: get_i32("m")?,
+            ef_construction: get_i32("ef_construction")?,
+            metric: get_metric()?,
+            quantize: get_quantize()?,
+        },
+        "Ivf" => IndexSpec::Ivf {
+            n_list: get_i32("n_list")?,
+            n_iters: get_i32("n_iters")?,
+            use_soar: get_bool("use_soar")?,
+            metric: get_metric()?,
+            quantize: get_quantize()?,
+        },
+        "Flat" => IndexSpec::Flat {
+            metric: get_metric()?,
+            quantize: get_quantize()?,
+        },
+        "Invert" => IndexSpec::Invert {
+            enable_range_optimization: get_bool("enable_range_optimization")?,
+        },
+        other => {
+            return Err(Error::InvalidArgument(format!(
+                "unknown index_type '{other}'"
+            )))
+        }
+    })
+}
+
+/// A snapshot container decoded by [`decode_snapshot`], ready for
+/// [`crate::collection::Collection::import_snapshot`] to rebuild a
+/// collection from.
+pub(crate) struct DecodedSnapshot {
+    pub(crate) schema: CollectionSchema,
+    pub(crate) indices: Vec<(String, IndexSpec)>,
+    pub(crate) docs: Vec<Vec<u8>>,
+}
+
+/// Version tag for the snapshot container format written by
+/// [`encode_snapshot`], so a future format change can be detected on import
+/// instead of silently misreading old bytes.
+const SNAPSHOT_VERSION: i64 = 1;
+
+/// Encode a [`crate::collection::Collection::export_snapshot`] container:
+/// `schema`'s CBOR encoding, each `(field, IndexSpec)` pair, and the
+/// already-encoded `docs` (empty for a schema-only export), behind a
+/// [`SNAPSHOT_VERSION`] header.
+pub(crate) fn encode_snapshot(
+    schema: &CollectionSchema,
+    indices: &[(String, IndexSpec)],
+    docs: &[Vec<u8>],
+) -> Result<Vec<u8>> {
+    let index_values: Vec<Value> = indices
+        .iter()
+        .map(|(field, spec)| {
+            Value::Map(vec![
+                (Value::Text("field".into()), Value::Text(field.clone())),
+                (Value::Text("spec".into()), index_spec_to_value(spec)),
+            ])
+        })
+        .collect();
+
+    let container = Value::Map(vec![
+        (
+            Value::Text("version".into()),
+            Value::Integer(SNAPSHOT_VERSION.into()),
+        ),
+        (
+            Value::Text("schema".into()),
+            Value::Bytes(schema.to_cbor()?),
+        ),
+        (Value::Text("indices".into()), Value::Array(index_values)),
+        (
+            Value::Text("docs".into()),
+            Value::Array(docs.iter().cloned().map(Value::Bytes).collect()),
+        ),
+    ]);
+
+    let mut buf = Vec::new();
+    ciborium::ser::into_writer(&container, &mut buf)
+        .map_err(|e| Error::InternalError(format!("failed to encode snapshot: {e}")))?;
+    Ok(buf)
+}
+
+/// Decode a snapshot container written by [`encode_snapshot`].
+pub(crate) fn decode_snapshot(bytes: &[u8]) -> Result<DecodedSnapshot> {
+    let value: Value = ciborium::de::from_reader(bytes)
+        .map_err(|e| Error::InvalidArgument(format!("failed to decode snapshot: {e}")))?;
+    let map = value
+        .into_map()
+        .map_err(|_| Error::InvalidArgument("snapshot must be a cbor map".into()))?;
+
+    let mut version = None;
+    let mut schema_bytes = None;
+    let mut index_values = Vec::new();
+    let mut doc_values = Vec::new();
+    for (key, val) in map {
+        match key.as_text() {
+            Some("version") => version = val.as_integer().and_then(|i| i64::try_from(i).ok()),
+            Some("schema") => schema_bytes = val.into_bytes().ok(),
+            Some("indices") => index_values = val.into_array().unwrap_or_default(),
+            Some("docs") => doc_values = val.into_array().unwrap_or_default(),
+            _ => {}
+        }
+    }
+
+    if version != Some(SNAPSHOT_VERSION) {
+        return Err(Error::InvalidArgument(format!(
+            "unsupported snapshot version: {version:?}"
+        )));
+    }
+
+    let schema_bytes =
+        schema_bytes.ok_or_else(|| Error::InvalidArgument("snapshot missing 'schema'".into()))?;
+    let schema = CollectionSchema::from_cbor(&schema_bytes)?;
+
+    let mut indices = Vec::with_capacity(index_values.len());
+    for index_value in index_values {
+        let index_map = index_value
+            .into_map()
+            .map_err(|_| Error::InvalidArgument("snapshot index entry must be a map".into()))?;
+        let mut field = None;
+        let mut spec_value = None;
+        for (key, val) in index_map {
+            match key.as_text() {
+                Some("field") => field = val.into_text().ok(),
+                Some("spec") => spec_value = Some(val),
+                _ => {}
+            }
+        }
+        let field = field
+            .ok_or_else(|| Error::InvalidArgument("snapshot index entry missing 'field'".into()))?;
+        let spec_value = spec_value
+            .ok_or_else(|| Error::InvalidArgument("snapshot index entry missing 'spec'".into()))?;
+        indices.push((field, index_spec_from_value(spec_value)?));
+    }
+
+    let mut docs = Vec::with_capacity(doc_values.len());
+    for doc_value in doc_values {
+        docs.push(
+            doc_value
+                .into_bytes()
+                .map_err(|_| Error::InvalidArgument("snapshot doc must be bytes".into()))?,
+        );
+    }
+
+    Ok(DecodedSnapshot {
+        schema,
+        indices,
+        docs,
+    })
+}
@@ -0,0 +1,1654 @@
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::path::Path;
+use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::batch::{BatchResults, BulkOptions, WriteBatch};
+use crate::digest::Digest;
+use crate::doc::{Doc, DocList, DocMap, WriteResults};
+use crate::embed::{Embedder, EmbedderMapping, IdentityEmbedder, SharedEmbedder};
+use crate::embed_queue::EmbeddingsQueueConfig;
+use crate::error::{check_status, Error, Result};
+use crate::ffi;
+use crate::keyword::KeywordIndex;
+use crate::query::{
+    ExplainedGroupResults, ExplainedResults, FusedResults, GroupByVectorQuery, GroupResults,
+    HybridQuery, HybridResults, MultiVectorQuery, VectorQuery,
+};
+use crate::schema::CollectionSchema;
+use crate::snapshot::{SnapshotId, SnapshotInfo};
+use crate::types::{DataType, IndexType, MetricType, QuantizeType};
+
+/// A collection of documents with vector search capabilities.
+///
+/// A Collection is the main entry point for working with zvec. It represents
+/// a collection of documents that can be searched using vector similarity.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::{create_and_open, CollectionSchema, Doc, VectorQuery, VectorSchema};
+///
+/// # fn main() -> zvec_bindings::Result<()> {
+/// let mut schema = CollectionSchema::new("my_collection");
+/// schema.add_field(VectorSchema::fp32("embedding", 128).into())?;
+///
+/// let collection = create_and_open("./my_db", schema)?;
+///
+/// // Insert documents
+/// let mut doc = Doc::id("doc_1");
+/// doc.set_vector("embedding", &[0.1, 0.2, 0.3])?;
+/// collection.insert(&[doc])?;
+///
+/// // Search
+/// let query = VectorQuery::new("embedding").topk(10).vector(&[0.1, 0.2, 0.3])?;
+/// let results = collection.query(query)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Collection {
+    ptr: *mut ffi::zvec_collection_t,
+    embedder_mappings: Vec<EmbedderMapping>,
+    /// Field name -> declared [`DataType`], captured from the schema at
+    /// construction/reopen time so the auto-embed resolution passes know
+    /// whether a target field is a sparse or dense vector without an extra
+    /// FFI round-trip per document.
+    field_types: HashMap<String, DataType>,
+    embedders: Mutex<HashMap<String, SharedEmbedder>>,
+    snapshots: Mutex<Vec<SnapshotInfo>>,
+    next_snapshot_id: AtomicU64,
+    keyword_indices: Mutex<HashMap<String, KeywordIndex>>,
+    embedding_cache: Mutex<HashMap<Digest, Vec<f32>>>,
+    embeddings_queue_config: Mutex<EmbeddingsQueueConfig>,
+    /// Backing directory for [`Self::in_memory`] collections, deleted on
+    /// drop. `None` for ordinary path-backed collections.
+    ephemeral_dir: Option<std::path::PathBuf>,
+    /// The schema this collection was created with, kept around for
+    /// [`Self::export_snapshot`]. `None` for a collection reopened via
+    /// [`Self::open`], since there is no native call to read a schema back
+    /// out of an already-created collection.
+    schema: Option<CollectionSchema>,
+    /// Field name -> [`IndexSpec`] for every index created via
+    /// [`Self::create_index`] on this `Collection` instance, so
+    /// [`Self::export_snapshot`] can record the construction arguments
+    /// needed to replay it with [`Self::create_index`] on import.
+    /// [`IndexParams`] otherwise can't be read back once built, and this
+    /// bookkeeping only covers indices created after this process opened
+    /// the collection, not ones already on disk.
+    index_specs: Mutex<HashMap<String, IndexSpec>>,
+}
+
+/// Counter used to give each [`Collection::in_memory`] call its own backing
+/// directory within the process temp dir, even across concurrent calls.
+static NEXT_EPHEMERAL_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Snapshot a schema's field name -> [`DataType`] mapping, for the auto-embed
+/// resolution passes to tell a sparse vector target field from a dense one.
+fn field_types_from_schema(schema: &CollectionSchema) -> HashMap<String, DataType> {
+    schema
+        .fields()
+        .iter()
+        .map(|f| (f.name().to_string(), f.data_type()))
+        .collect()
+}
+
+impl Collection {
+    pub fn create_and_open<P: AsRef<Path>>(path: P, schema: CollectionSchema) -> Result<Self> {
+        let path_str = path.as_ref().to_string_lossy().into_owned();
+        let path_c = CString::new(path_str).unwrap();
+        let embedder_mappings = schema.embedder_mappings().to_vec();
+        let field_types = field_types_from_schema(&schema);
+
+        let mut status: ffi::zvec_status_t = unsafe { std::mem::zeroed() };
+        let ptr = unsafe {
+            ffi::zvec_collection_create_and_open(
+                path_c.as_ptr(),
+                schema.ptr,
+                ptr::null_mut(),
+                &mut status,
+            )
+        };
+
+        check_status(status)?;
+
+        if ptr.is_null() {
+            return Err(crate::error::Error::InternalError(
+                "Failed to create collection: null pointer".into(),
+            ));
+        }
+
+        Ok(Self {
+            ptr,
+            embedder_mappings,
+            field_types,
+            embedders: Mutex::new(HashMap::new()),
+            snapshots: Mutex::new(Vec::new()),
+            next_snapshot_id: AtomicU64::new(1),
+            keyword_indices: Mutex::new(HashMap::new()),
+            embedding_cache: Mutex::new(HashMap::new()),
+            embeddings_queue_config: Mutex::new(EmbeddingsQueueConfig::default()),
+            ephemeral_dir: None,
+            schema: Some(schema),
+            index_specs: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Like [`Self::create_and_open`], but with `options` (e.g. a non-local
+    /// [`StorageBackend`] set via [`CollectionOptions::backend`]) passed
+    /// through to the native open call.
+    pub fn create_and_open_with_options<P: AsRef<Path>>(
+        path: P,
+        schema: CollectionSchema,
+        options: &CollectionOptions,
+    ) -> Result<Self> {
+        let path_str = path.as_ref().to_string_lossy().into_owned();
+        let path_c = CString::new(path_str).unwrap();
+        let embedder_mappings = schema.embedder_mappings().to_vec();
+        let field_types = field_types_from_schema(&schema);
+
+        let mut status: ffi::zvec_status_t = unsafe { std::mem::zeroed() };
+        let ptr = unsafe {
+            ffi::zvec_collection_create_and_open(
+                path_c.as_ptr(),
+                schema.ptr,
+                options.ptr,
+                &mut status,
+            )
+        };
+
+        check_status(status)?;
+
+        if ptr.is_null() {
+            return Err(crate::error::Error::InternalError(
+                "Failed to create collection: null pointer".into(),
+            ));
+        }
+
+        Ok(Self {
+            ptr,
+            embedder_mappings,
+            field_types,
+            embedders: Mutex::new(HashMap::new()),
+            snapshots: Mutex::new(Vec::new()),
+            next_snapshot_id: AtomicU64::new(1),
+            keyword_indices: Mutex::new(HashMap::new()),
+            embedding_cache: Mutex::new(HashMap::new()),
+            embeddings_queue_config: Mutex::new(EmbeddingsQueueConfig::default()),
+            ephemeral_dir: None,
+            schema: Some(schema),
+            index_specs: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Create a collection with no durable filesystem path, backed entirely
+    /// by a process-unique temporary directory that is deleted again when
+    /// the last handle to it drops.
+    ///
+    /// There is no true in-memory storage backend in this build of zvec, so
+    /// under the hood this is a path-backed collection whose path the
+    /// caller never sees and never has to clean up: [`Self::path`] returns
+    /// [`Error::NotSupported`] rather than the backing directory, and
+    /// [`Drop`] removes that directory once the collection closes. This is
+    /// useful for unit tests (no manual `tempdir()` bookkeeping), transient
+    /// caches, and staging data before a bulk commit to a persistent
+    /// collection.
+    pub fn in_memory(schema: CollectionSchema) -> Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "zvec-in-memory-{}-{}",
+            std::process::id(),
+            NEXT_EPHEMERAL_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            Error::InternalError(format!(
+                "failed to create backing directory for in-memory collection: {e}"
+            ))
+        })?;
+
+        let mut collection = match Self::create_and_open(&dir, schema) {
+            Ok(collection) => collection,
+            Err(err) => {
+                let _ = std::fs::remove_dir_all(&dir);
+                return Err(err);
+            }
+        };
+        collection.ephemeral_dir = Some(dir);
+        Ok(collection)
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path_str = path.as_ref().to_string_lossy().into_owned();
+        let path_c = CString::new(path_str).unwrap();
+
+        let mut status: ffi::zvec_status_t = unsafe { std::mem::zeroed() };
+        let ptr =
+            unsafe { ffi::zvec_collection_open(path_c.as_ptr(), ptr::null_mut(), &mut status) };
+
+        check_status(status)?;
+
+        if ptr.is_null() {
+            return Err(crate::error::Error::InternalError(
+                "Failed to open collection: null pointer".into(),
+            ));
+        }
+
+        Ok(Self {
+            ptr,
+            embedder_mappings: Vec::new(),
+            field_types: HashMap::new(),
+            embedders: Mutex::new(HashMap::new()),
+            snapshots: Mutex::new(Vec::new()),
+            next_snapshot_id: AtomicU64::new(1),
+            keyword_indices: Mutex::new(HashMap::new()),
+            embedding_cache: Mutex::new(HashMap::new()),
+            embeddings_queue_config: Mutex::new(EmbeddingsQueueConfig::default()),
+            ephemeral_dir: None,
+            schema: None,
+            index_specs: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Like [`Self::open`], but with `options` (e.g. a non-local
+    /// [`StorageBackend`] set via [`CollectionOptions::backend`]) passed
+    /// through to the native open call.
+    pub fn open_with_options<P: AsRef<Path>>(path: P, options: &CollectionOptions) -> Result<Self> {
+        let path_str = path.as_ref().to_string_lossy().into_owned();
+        let path_c = CString::new(path_str).unwrap();
+
+        let mut status: ffi::zvec_status_t = unsafe { std::mem::zeroed() };
+        let ptr = unsafe { ffi::zvec_collection_open(path_c.as_ptr(), options.ptr, &mut status) };
+
+        check_status(status)?;
+
+        if ptr.is_null() {
+            return Err(crate::error::Error::InternalError(
+                "Failed to open collection: null pointer".into(),
+            ));
+        }
+
+        Ok(Self {
+            ptr,
+            embedder_mappings: Vec::new(),
+            field_types: HashMap::new(),
+            embedders: Mutex::new(HashMap::new()),
+            snapshots: Mutex::new(Vec::new()),
+            next_snapshot_id: AtomicU64::new(1),
+            keyword_indices: Mutex::new(HashMap::new()),
+            embedding_cache: Mutex::new(HashMap::new()),
+            embeddings_queue_config: Mutex::new(EmbeddingsQueueConfig::default()),
+            ephemeral_dir: None,
+            schema: None,
+            index_specs: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Open an existing collection and re-apply the embedder field mappings
+    /// recorded on `schema` (via [`CollectionSchema::register_embedder`])
+    /// without recreating the collection itself.
+    ///
+    /// The on-disk schema is not re-validated against `schema` here; this
+    /// just restores the client-side text->vector mapping so `insert`,
+    /// `upsert`, and `VectorQuery::text` auto-embed the same fields they did
+    /// before the collection was closed. Call [`Self::set_embedder`]
+    /// afterwards to attach the actual [`Embedder`] implementation again,
+    /// since that isn't something schema metadata can carry.
+    pub fn open_with_schema<P: AsRef<Path>>(path: P, schema: &CollectionSchema) -> Result<Self> {
+        let mut collection = Self::open(path)?;
+        collection.embedder_mappings = schema.embedder_mappings().to_vec();
+        collection.field_types = field_types_from_schema(schema);
+        Ok(collection)
+    }
+
+    /// Register `embedder` as the implementation used to auto-embed
+    /// `field` (a `VectorFp32` field named as a mapping's `target_field`)
+    /// on `insert`/`upsert` and in [`VectorQuery::text`] queries against it.
+    ///
+    /// Until an embedder is registered for a mapped field, auto-embedding
+    /// falls back to [`IdentityEmbedder`], which never fails but also never
+    /// produces a meaningful vector.
+    ///
+    /// Replacing an already-registered embedder drops the entire
+    /// content-[`Digest`] cache, not just `field`'s entries: a [`Digest`] is
+    /// a hash with no embedder identity baked in (see [`crate::digest`]), so
+    /// there is no way to evict only the stale ones. This is a rare,
+    /// admin-time call, so paying for a full re-embed of in-flight text
+    /// after it runs is preferable to serving vectors from the embedder that
+    /// was just replaced.
+    pub fn set_embedder(&self, field: &str, embedder: impl Embedder + 'static) {
+        self.embedders
+            .lock()
+            .unwrap()
+            .insert(field.to_string(), Arc::new(embedder));
+        self.embedding_cache.lock().unwrap().clear();
+    }
+
+    /// Configure how `insert`/`upsert`/`update` batch and retry embedder
+    /// calls; see [`EmbeddingsQueueConfig`] for what each knob controls.
+    pub fn configure_embeddings_queue(&self, config: EmbeddingsQueueConfig) {
+        *self.embeddings_queue_config.lock().unwrap() = config;
+    }
+
+    /// Resolve the embedder to use for `target_field`: the one registered
+    /// via [`Self::set_embedder`], or an [`IdentityEmbedder`] sized from the
+    /// schema mapping if none was registered.
+    ///
+    /// Errors if `target_field` has neither a registered embedder nor a
+    /// schema mapping, since there is then no dimension to size a fallback
+    /// with.
+    fn resolve_embedder(&self, target_field: &str) -> Result<SharedEmbedder> {
+        if let Some(embedder) = self.embedders.lock().unwrap().get(target_field) {
+            return Ok(Arc::clone(embedder));
+        }
+        let mapping = self
+            .embedder_mappings
+            .iter()
+            .find(|m| m.target_field == target_field)
+            .ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "no embedder registered and no schema mapping found for field '{target_field}'"
+                ))
+            })?;
+        Ok(Arc::new(IdentityEmbedder::new(
+            mapping.target_dimension as usize,
+        )))
+    }
+
+    /// Resolve vectors for `pending` texts targeting `field`, consulting the
+    /// content-[`Digest`] cache before falling back to `embedder`.
+    ///
+    /// Only cache misses are embedded, split into batches (each retried on
+    /// rate limiting) per the collection's [`EmbeddingsQueueConfig`]; newly computed
+    /// vectors are written back keyed by digest so re-indexing an unchanged
+    /// corpus costs near-zero `embed` calls, and re-upserting a document
+    /// whose text is identical is a no-op for the embedder.
+    fn resolve_cached_embeddings(
+        &self,
+        field: &str,
+        embedder: &SharedEmbedder,
+        pending: &[(usize, &str)],
+    ) -> Result<Vec<Vec<f32>>> {
+        let digests: Vec<Digest> = pending
+            .iter()
+            .map(|(_, text)| Digest::compute(field, text, ""))
+            .collect();
+
+        let mut vectors: Vec<Option<Vec<f32>>> = {
+            let cache = self.embedding_cache.lock().unwrap();
+            digests.iter().map(|d| cache.get(d).cloned()).collect()
+        };
+
+        let miss_indices: Vec<usize> = vectors
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_none())
+            .map(|(i, _)| i)
+            .collect();
+
+        if !miss_indices.is_empty() {
+            let miss_texts: Vec<&str> = miss_indices.iter().map(|&i| pending[i].1).collect();
+            let config = *self.embeddings_queue_config.lock().unwrap();
+
+            let mut embedded: Vec<Vec<f32>> = Vec::with_capacity(miss_texts.len());
+            let mut offset = 0;
+            for batch_size in config.chunk_sizes(&miss_texts) {
+                let batch_texts = &miss_texts[offset..offset + batch_size];
+                let batch_vectors = config.with_retry(|| embedder.embed(batch_texts))?;
+
+                if batch_vectors.len() != batch_texts.len() {
+                    return Err(Error::InternalError(format!(
+                        "embedder for '{field}' returned {} vectors for {} inputs",
+                        batch_vectors.len(),
+                        batch_texts.len()
+                    )));
+                }
+
+                embedded.extend(batch_vectors);
+                offset += batch_size;
+            }
+
+            let mut cache = self.embedding_cache.lock().unwrap();
+            for (&i, vector) in miss_indices.iter().zip(embedded) {
+                cache.insert(digests[i].clone(), vector.clone());
+                vectors[i] = Some(vector);
+            }
+        }
+
+        Ok(vectors
+            .into_iter()
+            .map(|v| v.expect("filled above"))
+            .collect())
+    }
+
+    /// Whether `field` was declared as a sparse vector type in the schema
+    /// this collection was opened with. `false` for a plain [`Self::open`]
+    /// with no schema, since field types aren't otherwise retained.
+    fn is_sparse_field(&self, field: &str) -> bool {
+        self.field_types
+            .get(field)
+            .is_some_and(|t| t.is_sparse_vector())
+    }
+
+    /// Resolve sparse vectors for `pending` texts via `embedder.embed_sparse`,
+    /// batched the same way as [`Self::resolve_cached_embeddings`].
+    ///
+    /// Unlike the dense path, misses are never cached: the content-[`Digest`]
+    /// cache is keyed to dense `Vec<f32>` vectors, and sparse auto-embedding
+    /// is expected to be rare enough that this isn't worth a second cache.
+    fn resolve_sparse_embeddings(
+        &self,
+        field: &str,
+        embedder: &SharedEmbedder,
+        pending: &[(usize, &str)],
+    ) -> Result<Vec<(Vec<u32>, Vec<f32>)>> {
+        let texts: Vec<&str> = pending.iter().map(|(_, text)| *text).collect();
+        let config = *self.embeddings_queue_config.lock().unwrap();
+
+        let mut embedded: Vec<(Vec<u32>, Vec<f32>)> = Vec::with_capacity(texts.len());
+        let mut offset = 0;
+        for batch_size in config.chunk_sizes(&texts) {
+            let batch_texts = &texts[offset..offset + batch_size];
+            let batch_vectors = config.with_retry(|| embedder.embed_sparse(batch_texts))?;
+
+            if batch_vectors.len() != batch_texts.len() {
+                return Err(Error::InternalError(format!(
+                    "embedder for '{field}' returned {} sparse vectors for {} inputs",
+                    batch_vectors.len(),
+                    batch_texts.len()
+                )));
+            }
+
+            embedded.extend(batch_vectors);
+            offset += batch_size;
+        }
+
+        Ok(embedded)
+    }
+
+    /// Look up embeddings already cached by [`Self::resolve_cached_embeddings`]
+    /// under `digests`, without calling any embedder for misses.
+    ///
+    /// Digests are computed with the same `(field, text, model_version)`
+    /// inputs as [`Digest::compute`]; misses are simply absent from the
+    /// returned map.
+    pub fn embeddings_for_digests(&self, digests: &[Digest]) -> HashMap<Digest, Vec<f32>> {
+        let cache = self.embedding_cache.lock().unwrap();
+        digests
+            .iter()
+            .filter_map(|d| cache.get(d).map(|v| (d.clone(), v.clone())))
+            .collect()
+    }
+
+    /// Auto-populate each mapping's `target_field` from `source_field` text
+    /// for every doc that has the source but is missing the target, embedding
+    /// each mapping's pending text across all of `docs` together (split into
+    /// batches per [`EmbeddingsQueueConfig`]).
+    fn apply_embedders(&self, docs: &[Doc]) -> Result<()> {
+        for mapping in &self.embedder_mappings {
+            let pending: Vec<(usize, &str)> = docs
+                .iter()
+                .enumerate()
+                .filter(|(_, doc)| !doc.has_value(&mapping.target_field))
+                .filter_map(|(i, doc)| doc.get_string(&mapping.source_field).map(|text| (i, text)))
+                .collect();
+
+            if pending.is_empty() {
+                continue;
+            }
+
+            let embedder = self.resolve_embedder(&mapping.target_field)?;
+
+            if self.is_sparse_field(&mapping.target_field) {
+                let vectors =
+                    self.resolve_sparse_embeddings(&mapping.target_field, &embedder, &pending)?;
+                let field_c = CString::new(mapping.target_field.as_str()).unwrap();
+                for ((i, _), (indices, values)) in pending.iter().zip(vectors) {
+                    let status = unsafe {
+                        ffi::zvec_doc_set_sparse_vector_fp32(
+                            docs[*i].ptr,
+                            field_c.as_ptr(),
+                            indices.as_ptr(),
+                            indices.len(),
+                            values.as_ptr(),
+                            values.len(),
+                        )
+                    };
+                    check_status(status)?;
+                }
+                continue;
+            }
+
+            let vectors =
+                self.resolve_cached_embeddings(&mapping.target_field, &embedder, &pending)?;
+
+            let field_c = CString::new(mapping.target_field.as_str()).unwrap();
+            for ((i, _), vector) in pending.iter().zip(vectors) {
+                let status = unsafe {
+                    ffi::zvec_doc_set_vector_fp32(
+                        docs[*i].ptr,
+                        field_c.as_ptr(),
+                        vector.as_ptr(),
+                        vector.len(),
+                    )
+                };
+                check_status(status)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve every [`Doc::set_text`] staged directly against a vector
+    /// field, embedding each distinct field's pending text across all of
+    /// `docs` together (split into batches per [`EmbeddingsQueueConfig`]).
+    ///
+    /// Unlike [`Self::apply_embedders`], this needs no
+    /// [`EmbedderMapping`](crate::embed::EmbedderMapping): the text is staged
+    /// against the vector field itself, so only a directly registered
+    /// [`Self::set_embedder`] can resolve it. Runs before `apply_embedders`
+    /// so a field set both ways prefers the directly staged text.
+    fn apply_text_fields(&self, docs: &[Doc]) -> Result<()> {
+        let mut by_field: HashMap<&str, Vec<(usize, &str)>> = HashMap::new();
+        for (i, doc) in docs.iter().enumerate() {
+            for (field, text) in doc.pending_text() {
+                if doc.has_value(field) {
+                    continue;
+                }
+                by_field
+                    .entry(field.as_str())
+                    .or_default()
+                    .push((i, text.as_str()));
+            }
+        }
+
+        for (field, pending) in by_field {
+            let embedder = self.resolve_embedder(field)?;
+
+            if self.is_sparse_field(field) {
+                let vectors = self.resolve_sparse_embeddings(field, &embedder, &pending)?;
+                let field_c = CString::new(field).unwrap();
+                for ((i, _), (indices, values)) in pending.iter().zip(vectors) {
+                    let status = unsafe {
+                        ffi::zvec_doc_set_sparse_vector_fp32(
+                            docs[*i].ptr,
+                            field_c.as_ptr(),
+                            indices.as_ptr(),
+                            indices.len(),
+                            values.as_ptr(),
+                            values.len(),
+                        )
+                    };
+                    check_status(status)?;
+                }
+                continue;
+            }
+
+            let vectors = self.resolve_cached_embeddings(field, &embedder, &pending)?;
+
+            let field_c = CString::new(field).unwrap();
+            for ((i, _), vector) in pending.iter().zip(vectors) {
+                let status = unsafe {
+                    ffi::zvec_doc_set_vector_fp32(
+                        docs[*i].ptr,
+                        field_c.as_ptr(),
+                        vector.as_ptr(),
+                        vector.len(),
+                    )
+                };
+                check_status(status)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-index `field` on every doc in `docs` that carries a string value
+    /// for it, for each field registered via [`Self::create_text_index`].
+    fn apply_keyword_indices(&self, docs: &[Doc]) {
+        let mut indices = self.keyword_indices.lock().unwrap();
+        if indices.is_empty() {
+            return;
+        }
+        for (field, index) in indices.iter_mut() {
+            for doc in docs {
+                if let Some(text) = doc.get_string(field) {
+                    index.index(doc.pk(), text);
+                }
+            }
+        }
+    }
+
+    /// Build (or rebuild) a client-side keyword index over `field` for use
+    /// as the keyword leg of a [`HybridQuery`]'s
+    /// [`HybridQuery::keyword`](crate::query::HybridQuery::keyword).
+    ///
+    /// There is no FFI binding for a native full-text index type, so unlike
+    /// [`Self::create_index`] this registers a lightweight inverted index
+    /// this crate maintains itself: every doc already in the collection
+    /// must be re-inserted or re-upserted to be picked up (there is no scan
+    /// API to backfill existing rows), and `insert`/`upsert`/`update` keep
+    /// it current for `field` from then on. `delete` removes a pk from it;
+    /// `delete_by_filter` cannot, since it doesn't report which pks matched.
+    pub fn create_text_index(&self, field: &str) -> Result<()> {
+        self.keyword_indices
+            .lock()
+            .unwrap()
+            .entry(field.to_string())
+            .or_insert_with(KeywordIndex::new);
+        Ok(())
+    }
+
+    /// Stop maintaining the client-side keyword index over `field` built by
+    /// [`Self::create_text_index`].
+    pub fn drop_text_index(&self, field: &str) {
+        self.keyword_indices.lock().unwrap().remove(field);
+    }
+
+    /// Get the filesystem path where this collection is stored.
+    ///
+    /// Returns [`Error::NotSupported`] for a [`Self::in_memory`] collection,
+    /// which has no path meaningful to callers.
+    pub fn path(&self) -> Result<String> {
+        if self.ephemeral_dir.is_some() {
+            return Err(Error::NotSupported(
+                "in-memory collections have no durable filesystem path".into(),
+            ));
+        }
+
+        let mut path_ptr: *const std::os::raw::c_char = ptr::null();
+        let status = unsafe { ffi::zvec_collection_path(self.ptr, &mut path_ptr) };
+        check_status(status)?;
+
+        if path_ptr.is_null() {
+            return Ok(String::new());
+        }
+
+        Ok(unsafe {
+            std::ffi::CStr::from_ptr(path_ptr)
+                .to_string_lossy()
+                .into_owned()
+        })
+    }
+
+    /// Start a [`WriteBatch`] for queuing a mixed sequence of inserts,
+    /// upserts, updates, and deletes to submit together via
+    /// [`WriteBatch::commit`].
+    pub fn batch(&self) -> WriteBatch<'_> {
+        WriteBatch::new(self)
+    }
+
+    /// Insert documents into the collection.
+    ///
+    /// Returns a [`WriteResults`] indicating the success or failure of each insert.
+    pub fn insert(&self, docs: &[Doc]) -> Result<WriteResults> {
+        self.apply_text_fields(docs)?;
+        self.apply_embedders(docs)?;
+        self.apply_keyword_indices(docs);
+        let mut doc_ptrs: Vec<*mut ffi::zvec_doc_t> = docs.iter().map(|d| d.ptr).collect();
+        let mut results: ffi::zvec_write_results_t = unsafe { std::mem::zeroed() };
+
+        let status = unsafe {
+            ffi::zvec_collection_insert(
+                self.ptr,
+                doc_ptrs.as_mut_ptr(),
+                doc_ptrs.len(),
+                &mut results,
+            )
+        };
+
+        check_status(status)?;
+        Ok(WriteResults { inner: results })
+    }
+
+    /// Insert `docs` in bounded-size chunks instead of one `insert` call
+    /// over the whole slice, retrying a chunk's failed documents and
+    /// reporting progress as each chunk completes.
+    ///
+    /// `opts.chunk_size` bounds how many documents are handed to a single
+    /// `insert` call, so memory use stays bounded regardless of how many
+    /// documents are passed in. After a chunk's `insert`, any document
+    /// whose result is an [`Error::is_transient`] failure is retried on
+    /// its own (up to `opts.max_retries` times) by re-inserting just that
+    /// one document - non-transient failures (e.g. a malformed document)
+    /// are left as-is. `opts.flush_every_n_chunks`, if set, calls
+    /// [`Self::flush`] after every `n` chunks. `progress` is called with
+    /// `(documents done, total documents)` once per chunk.
+    ///
+    /// Returns a [`BatchResults`] rather than a [`WriteResults`]: a
+    /// retried document's final outcome can differ from what the chunk's
+    /// own native [`WriteResults`] reported, and there is no way to patch
+    /// a single entry of a native result in place, so the merged
+    /// per-document outcomes are assembled in Rust instead (same
+    /// aggregate type [`Collection::batch`] uses).
+    pub fn bulk_insert(
+        &self,
+        docs: &[Doc],
+        opts: BulkOptions,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<BatchResults> {
+        let total = docs.len();
+        let chunk_size = opts.chunk_size.max(1);
+        let mut done = 0;
+        let mut outcomes: Vec<Result<()>> = Vec::with_capacity(total);
+
+        for (chunk_idx, chunk) in docs.chunks(chunk_size).enumerate() {
+            let results = self.insert(chunk)?;
+            let mut statuses: Vec<Result<()>> = results.iter().collect();
+
+            for _ in 0..opts.max_retries {
+                let retryable: Vec<usize> = statuses
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, r)| match r {
+                        Err(e) if e.is_transient() => Some(i),
+                        _ => None,
+                    })
+                    .collect();
+                if retryable.is_empty() {
+                    break;
+                }
+                for i in retryable {
+                    statuses[i] = match self.insert(std::slice::from_ref(&chunk[i])) {
+                        Ok(single) => single.get(0).unwrap_or(Ok(())),
+                        Err(e) => Err(e),
+                    };
+                }
+            }
+
+            done += chunk.len();
+            progress(done, total);
+            outcomes.extend(statuses);
+
+            if let Some(n) = opts.flush_every_n_chunks {
+                if n > 0 && (chunk_idx + 1) % n == 0 {
+                    self.flush()?;
+                }
+            }
+        }
+
+        Ok(BatchResults::from_materialized(outcomes))
+    }
+
+    /// Upsert documents into the collection.
+    ///
+    /// If a document with the same primary key exists, it will be updated.
+    /// Otherwise, it will be inserted.
+    pub fn upsert(&self, docs: &[Doc]) -> Result<WriteResults> {
+        self.apply_text_fields(docs)?;
+        self.apply_embedders(docs)?;
+        self.apply_keyword_indices(docs);
+        let mut doc_ptrs: Vec<*mut ffi::zvec_doc_t> = docs.iter().map(|d| d.ptr).collect();
+        let mut results: ffi::zvec_write_results_t = unsafe { std::mem::zeroed() };
+
+        let status = unsafe {
+            ffi::zvec_collection_upsert(
+                self.ptr,
+                doc_ptrs.as_mut_ptr(),
+                doc_ptrs.len(),
+                &mut results,
+            )
+        };
+
+        check_status(status)?;
+        Ok(WriteResults { inner: results })
+    }
+
+    /// Update existing documents in the collection.
+    ///
+    /// Documents must already exist in the collection.
+    pub fn update(&self, docs: &[Doc]) -> Result<WriteResults> {
+        self.apply_text_fields(docs)?;
+        self.apply_keyword_indices(docs);
+        let mut doc_ptrs: Vec<*mut ffi::zvec_doc_t> = docs.iter().map(|d| d.ptr).collect();
+        let mut results: ffi::zvec_write_results_t = unsafe { std::mem::zeroed() };
+
+        let status = unsafe {
+            ffi::zvec_collection_update(
+                self.ptr,
+                doc_ptrs.as_mut_ptr(),
+                doc_ptrs.len(),
+                &mut results,
+            )
+        };
+
+        check_status(status)?;
+        Ok(WriteResults { inner: results })
+    }
+
+    /// Delete documents by primary key.
+    pub fn delete(&self, pks: &[&str]) -> Result<WriteResults> {
+        let pk_cstrings: Vec<CString> = pks.iter().map(|pk| CString::new(*pk).unwrap()).collect();
+        let mut pk_ptrs: Vec<*const std::os::raw::c_char> =
+            pk_cstrings.iter().map(|pk| pk.as_ptr()).collect();
+        let mut results: ffi::zvec_write_results_t = unsafe { std::mem::zeroed() };
+
+        let status = unsafe {
+            ffi::zvec_collection_delete(self.ptr, pk_ptrs.as_mut_ptr(), pk_ptrs.len(), &mut results)
+        };
+
+        check_status(status)?;
+
+        let mut indices = self.keyword_indices.lock().unwrap();
+        for index in indices.values_mut() {
+            for pk in pks {
+                index.remove(pk);
+            }
+        }
+
+        Ok(WriteResults { inner: results })
+    }
+
+    /// Delete documents matching a filter expression.
+    pub fn delete_by_filter(&self, filter: &str) -> Result<()> {
+        let filter_c = CString::new(filter).unwrap();
+        let status = unsafe { ffi::zvec_collection_delete_by_filter(self.ptr, filter_c.as_ptr()) };
+        check_status(status)
+    }
+
+    /// Execute a vector similarity search query.
+    ///
+    /// Returns a [`DocList`] containing the matching documents.
+    pub fn query(&self, query: VectorQuery) -> Result<DocList> {
+        if let Some(snapshot) = query.required_snapshot() {
+            self.check_snapshot(snapshot)?;
+        }
+        let query = self.resolve_query_text(query)?;
+        let mut results: ffi::zvec_doc_list_t = unsafe { std::mem::zeroed() };
+        let status = unsafe { ffi::zvec_collection_query(self.ptr, query.ptr, &mut results) };
+        check_status(status)?;
+        Ok(DocList { inner: results })
+    }
+
+    /// Capture the current committed state as a new, immutable, named
+    /// snapshot and return its id.
+    ///
+    /// Flushes pending writes first so the snapshot reflects what has
+    /// actually been committed. See [`crate::snapshot`] for what a
+    /// snapshot does and does not pin in this build.
+    pub fn snapshot(&self, label: &str) -> Result<SnapshotId> {
+        self.flush()?;
+        let id = SnapshotId(self.next_snapshot_id.fetch_add(1, Ordering::SeqCst));
+        self.snapshots.lock().unwrap().push(SnapshotInfo {
+            id,
+            label: label.to_string(),
+            captured_at: std::time::SystemTime::now(),
+        });
+        Ok(id)
+    }
+
+    /// All snapshots captured on this `Collection` instance, in capture
+    /// order.
+    pub fn list_snapshots(&self) -> Vec<SnapshotInfo> {
+        self.snapshots.lock().unwrap().clone()
+    }
+
+    /// Release a snapshot's pin, letting [`Self::optimize`] reclaim segments
+    /// that were only being kept around for it.
+    pub fn delete_snapshot(&self, id: SnapshotId) -> Result<()> {
+        let mut snapshots = self.snapshots.lock().unwrap();
+        let len_before = snapshots.len();
+        snapshots.retain(|s| s.id != id);
+        if snapshots.len() == len_before {
+            return Err(Error::NotFound(format!("snapshot {id:?} not found")));
+        }
+        Ok(())
+    }
+
+    /// Error if `id` isn't a snapshot currently held by this collection.
+    fn check_snapshot(&self, id: SnapshotId) -> Result<()> {
+        if self.snapshots.lock().unwrap().iter().any(|s| s.id == id) {
+            Ok(())
+        } else {
+            Err(Error::NotFound(format!("snapshot {id:?} not found")))
+        }
+    }
+
+    /// If `query` was built with [`VectorQuery::text`], embed the pending
+    /// text with this field's embedder and fill in the query vector.
+    /// Queries built with [`VectorQuery::vector`]/`sparse_vector` pass
+    /// through unchanged.
+    fn resolve_query_text(&self, query: VectorQuery) -> Result<VectorQuery> {
+        match query.pending_text() {
+            Some(text) => {
+                let embedder = self.resolve_embedder(query.field_name())?;
+                let vector =
+                    embedder.embed(&[text])?.into_iter().next().ok_or_else(|| {
+                        Error::InternalError("embedder returned no vector".into())
+                    })?;
+                query.vector(&vector)
+            }
+            None => Ok(query),
+        }
+    }
+
+    /// If `query` was built with [`GroupByVectorQuery::text`], embed the
+    /// pending text with this field's embedder and fill in the query vector.
+    /// Queries built with [`GroupByVectorQuery::vector`] pass through
+    /// unchanged.
+    fn resolve_group_by_query_text(&self, query: GroupByVectorQuery) -> Result<GroupByVectorQuery> {
+        match query.pending_text() {
+            Some(text) => {
+                let embedder = self.resolve_embedder(query.field_name())?;
+                let vector =
+                    embedder.embed(&[text])?.into_iter().next().ok_or_else(|| {
+                        Error::InternalError("embedder returned no vector".into())
+                    })?;
+                query.vector(&vector)
+            }
+            None => Ok(query),
+        }
+    }
+
+    /// Execute `query` and return a [`ScoreDetails`](crate::query::ScoreDetails)
+    /// breakdown alongside each doc.
+    ///
+    /// `query` must have been built with [`VectorQuery::explain`]`(true)`.
+    pub fn query_explained(&self, query: VectorQuery) -> Result<ExplainedResults> {
+        if !query.is_explain() {
+            return Err(Error::InvalidArgument(
+                "query_explained requires a query built with .explain(true)".into(),
+            ));
+        }
+        let metric = query.metric_type();
+        let filter_matched = query.has_filter().then_some(true);
+        let query = self.resolve_query_text(query)?;
+        let mut results: ffi::zvec_doc_list_t = unsafe { std::mem::zeroed() };
+        let status = unsafe { ffi::zvec_collection_query(self.ptr, query.ptr, &mut results) };
+        check_status(status)?;
+        Ok(ExplainedResults::new(
+            DocList { inner: results },
+            metric,
+            filter_matched,
+        ))
+    }
+
+    /// Execute a grouped vector similarity search query.
+    ///
+    /// Groups results by a specified field value. If `query` was built with
+    /// [`GroupByVectorQuery::text`], the text is embedded with the field's
+    /// registered embedder before the query runs.
+    pub fn group_by_query(&self, query: GroupByVectorQuery) -> Result<GroupResults> {
+        let query = self.resolve_group_by_query_text(query)?;
+        let mut results: ffi::zvec_group_results_t = unsafe { std::mem::zeroed() };
+        let status =
+            unsafe { ffi::zvec_collection_group_by_query(self.ptr, query.ptr, &mut results) };
+        check_status(status)?;
+        Ok(GroupResults { inner: results })
+    }
+
+    /// Execute `query` and return a [`ScoreDetails`](crate::query::ScoreDetails)
+    /// breakdown, normalized within each group, alongside each group's docs.
+    ///
+    /// `query` must have been built with [`GroupByVectorQuery::explain`]`(true)`.
+    pub fn group_by_query_explained(
+        &self,
+        query: GroupByVectorQuery,
+    ) -> Result<ExplainedGroupResults> {
+        if !query.is_explain() {
+            return Err(Error::InvalidArgument(
+                "group_by_query_explained requires a query built with .explain(true)".into(),
+            ));
+        }
+        let metric = query.metric_type();
+        let filter_matched = query.has_filter().then_some(true);
+        let query = self.resolve_group_by_query_text(query)?;
+        let mut results: ffi::zvec_group_results_t = unsafe { std::mem::zeroed() };
+        let status =
+            unsafe { ffi::zvec_collection_group_by_query(self.ptr, query.ptr, &mut results) };
+        check_status(status)?;
+        Ok(ExplainedGroupResults::new(
+            GroupResults { inner: results },
+            metric,
+            filter_matched,
+        ))
+    }
+
+    /// Execute a hybrid dense + sparse (+ keyword, + any
+    /// [`HybridQuery::extra_dense`] legs) search, fusing all ranked lists
+    /// client-side per [`HybridQuery`]'s chosen [`crate::query::FusionMethod`].
+    ///
+    /// Runs each leg as an ordinary [`VectorQuery`] against its field over a
+    /// widened candidate pool, then fuses.
+    pub fn hybrid_query(&self, query: HybridQuery) -> Result<HybridResults> {
+        let candidate_k = query.candidate_k();
+
+        let dense = match (&query.dense_field, &query.dense_vector) {
+            (Some(field), Some(vector)) => {
+                let q = VectorQuery::new(field).topk(candidate_k).vector(vector)?;
+                Some(self.query(q)?)
+            }
+            _ => None,
+        };
+
+        let sparse = match (
+            &query.sparse_field,
+            &query.sparse_indices,
+            &query.sparse_values,
+        ) {
+            (Some(field), Some(indices), Some(values)) => {
+                let q = VectorQuery::new(field)
+                    .topk(candidate_k)
+                    .sparse_vector(indices, values)?;
+                Some(self.query(q)?)
+            }
+            _ => None,
+        };
+
+        let mut extra = Vec::with_capacity(query.extra_dense.len());
+        for (field, vector) in &query.extra_dense {
+            let q = VectorQuery::new(field).topk(candidate_k).vector(vector)?;
+            extra.push((field.clone(), self.query(q)?));
+        }
+
+        let (keyword_ranked, keyword_docs) = match (&query.keyword_field, &query.keyword_text) {
+            (Some(field), Some(text)) => {
+                let ranked = self
+                    .keyword_indices
+                    .lock()
+                    .unwrap()
+                    .get(field)
+                    .ok_or_else(|| {
+                        Error::InvalidArgument(format!(
+                            "no keyword index registered for '{field}'; call create_text_index first"
+                        ))
+                    })?
+                    .search(text, candidate_k);
+                let pks: Vec<&str> = ranked.iter().map(|(pk, _)| pk.as_str()).collect();
+                let docs = self.fetch(&pks)?;
+                (Some(ranked), Some(docs))
+            }
+            _ => (None, None),
+        };
+
+        Ok(HybridResults::fuse(
+            dense,
+            sparse,
+            keyword_ranked,
+            keyword_docs,
+            extra,
+            query.fusion,
+            query.topk,
+            query.explain,
+            query.dense_weight,
+            query.sparse_weight,
+        ))
+    }
+
+    /// Run several [`VectorQuery`] legs (e.g. over different embedding
+    /// fields, or the same field with different probe parameters) and fuse
+    /// their ranked outputs with Reciprocal Rank Fusion.
+    ///
+    /// Each query is run in turn via [`Self::query`] and the resulting
+    /// [`DocList`]s are merged with [`FusedResults::fuse`] — the same
+    /// RRF pass [`Self::hybrid_query`] uses to combine its dense/sparse/
+    /// keyword legs. `k` is the RRF smoothing constant (`None` defaults to
+    /// 60.0; smaller values weight top ranks more sharply), and `topk`
+    /// bounds the number of fused hits returned.
+    ///
+    /// Takes `queries` by value rather than as a slice: [`VectorQuery`]
+    /// wraps a raw FFI pointer and isn't `Clone`, and [`Self::query`]
+    /// already consumes its argument by value, so there is no way to run
+    /// each leg from a borrowed `&[VectorQuery]` without cloning. Returns
+    /// [`FusedResults`] rather than a [`DocList`] for the same reason
+    /// [`Self::hybrid_query`] does: a fused score has nowhere to live on a
+    /// raw FFI-backed `DocList`/`Doc`, since there's no setter for a doc's
+    /// score slot. `FusedResults` keeps every leg's `DocList` alive so the
+    /// fused hits can still be read back out with their original docs.
+    pub fn fused_query(
+        &self,
+        queries: Vec<VectorQuery>,
+        k: Option<f64>,
+        topk: usize,
+    ) -> Result<FusedResults> {
+        let mut lists = Vec::with_capacity(queries.len());
+        for query in queries {
+            lists.push(self.query(query)?);
+        }
+        Ok(FusedResults::fuse(lists, k, topk))
+    }
+
+    /// Search several dense-vector fields of the same documents (e.g. a
+    /// title embedding and a body embedding) and fuse the per-field ranked
+    /// lists into one result, weighting each field's contribution per
+    /// [`MultiVectorQuery::field`].
+    ///
+    /// Each leg is run as its own [`VectorQuery`] against
+    /// [`MultiVectorQuery::candidate_k`] candidates, inheriting `query`'s
+    /// `.filter()`/`.output_fields()`, then combined with
+    /// [`FusedResults::fuse_weighted`] - the weighted counterpart of the
+    /// plain RRF pass [`Self::fused_query`]/[`Self::hybrid_query`] use.
+    pub fn multi_vector_query(&self, query: MultiVectorQuery) -> Result<FusedResults> {
+        let candidate_k = query.candidate_k();
+        let output_fields: Option<Vec<&str>> = query
+            .output_fields
+            .as_ref()
+            .map(|fields| fields.iter().map(String::as_str).collect());
+
+        let mut lists = Vec::with_capacity(query.legs.len());
+        for leg in &query.legs {
+            let mut q = VectorQuery::new(&leg.field).topk(candidate_k);
+            if let Some(filter) = &query.filter {
+                q = q.filter(filter);
+            }
+            if let Some(fields) = &output_fields {
+                q = q.output_fields(fields);
+            }
+            let q = q.vector(&leg.vector)?;
+            lists.push((self.query(q)?, leg.weight));
+        }
+        Ok(FusedResults::fuse_weighted(lists, None, query.topk))
+    }
+
+    /// Fetch documents by primary key.
+    ///
+    /// Returns a [`DocMap`] mapping primary keys to documents.
+    pub fn fetch(&self, pks: &[&str]) -> Result<DocMap> {
+        let pk_cstrings: Vec<CString> = pks.iter().map(|pk| CString::new(*pk).unwrap()).collect();
+        let mut pk_ptrs: Vec<*const std::os::raw::c_char> =
+            pk_cstrings.iter().map(|pk| pk.as_ptr()).collect();
+        let mut results: ffi::zvec_doc_map_t = unsafe { std::mem::zeroed() };
+
+        let status = unsafe {
+            ffi::zvec_collection_fetch(self.ptr, pk_ptrs.as_mut_ptr(), pk_ptrs.len(), &mut results)
+        };
+
+        check_status(status)?;
+        Ok(DocMap { inner: results })
+    }
+
+    /// Create an index on a vector field.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - Name of the vector field to index
+    /// * `params` - Index parameters (HNSW, IVF, FLAT, etc.)
+    pub fn create_index(&self, column_name: &str, params: IndexParams) -> Result<()> {
+        let spec = params.spec();
+        let column_c = CString::new(column_name).unwrap();
+        let status = unsafe {
+            ffi::zvec_collection_create_index(
+                self.ptr,
+                column_c.as_ptr(),
+                params.ptr,
+                ptr::null_mut(),
+            )
+        };
+        check_status(status)?;
+        self.index_specs
+            .lock()
+            .unwrap()
+            .insert(column_name.to_string(), spec);
+        Ok(())
+    }
+
+    /// Drop an index from a column.
+    pub fn drop_index(&self, column_name: &str) -> Result<()> {
+        let column_c = CString::new(column_name).unwrap();
+        let status = unsafe { ffi::zvec_collection_drop_index(self.ptr, column_c.as_ptr()) };
+        check_status(status)?;
+        self.index_specs.lock().unwrap().remove(column_name);
+        Ok(())
+    }
+
+    /// Optimize the collection for better search performance.
+    ///
+    /// Refuses to run while any [`SnapshotId`] is still pinned (see
+    /// [`Self::snapshot`]/[`Self::delete_snapshot`]), since compaction could
+    /// otherwise drop segments a snapshot still needs.
+    pub fn optimize(&self) -> Result<()> {
+        if !self.snapshots.lock().unwrap().is_empty() {
+            return Err(Error::FailedPrecondition(
+                "cannot optimize while snapshots are pinned; call delete_snapshot first".into(),
+            ));
+        }
+        let status = unsafe { ffi::zvec_collection_optimize(self.ptr, ptr::null_mut()) };
+        check_status(status)
+    }
+
+    /// Flush pending writes to disk.
+    pub fn flush(&self) -> Result<()> {
+        let status = unsafe { ffi::zvec_collection_flush(self.ptr) };
+        check_status(status)
+    }
+
+    /// Destroy the collection and delete all data.
+    pub fn destroy(self) -> Result<()> {
+        let status = unsafe { ffi::zvec_collection_destroy_storage(self.ptr) };
+        check_status(status)
+    }
+
+    /// Export this collection's schema, index params, and the documents
+    /// named in `pks` to a single self-describing CBOR file at `out`, for
+    /// backup, moving a collection to another machine, or cloning one
+    /// without re-running inserts and re-embedding. See
+    /// [`Self::import_snapshot`] for the reverse direction.
+    ///
+    /// Deviates from a plain "export everything" call in two ways the FFI
+    /// surface forces:
+    ///
+    /// - It needs `pks` rather than walking the whole collection: there is
+    ///   no native "enumerate every document" call, only fetch-by-key and
+    ///   similarity search, so the caller has to say which documents to
+    ///   include.
+    /// - It only works on a collection opened via [`Self::create_and_open`]
+    ///   or [`Self::in_memory`] in this process; one opened via
+    ///   [`Self::open`] has no schema to export, since there is no native
+    ///   call to read an existing collection's schema back out, and returns
+    ///   [`Error::NotSupported`] instead.
+    ///
+    /// `options.schema_only` skips documents entirely, producing a template
+    /// snapshot other callers can import to create empty collections with
+    /// the same shape.
+    #[cfg(feature = "cbor")]
+    pub fn export_snapshot<P: AsRef<Path>>(
+        &self,
+        pks: &[&str],
+        out: P,
+        options: ExportOptions,
+    ) -> Result<()> {
+        let schema = self.schema.as_ref().ok_or_else(|| {
+            Error::NotSupported(
+                "export_snapshot needs the schema this collection was created with; \
+                 a collection reopened via Collection::open doesn't retain one"
+                    .to_string(),
+            )
+        })?;
+
+        let mut doc_blobs = Vec::new();
+        if !options.schema_only {
+            let fetched = self.fetch(pks)?;
+            for pk in pks {
+                let doc = fetched
+                    .get(pk)
+                    .ok_or_else(|| Error::NotFound(format!("pk '{pk}' not found for export")))?;
+                doc_blobs.push(doc.to_cbor(schema)?);
+            }
+        }
+
+        let indices: Vec<(String, IndexSpec)> = self
+            .index_specs
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(field, spec)| (field.clone(), *spec))
+            .collect();
+
+        let bytes = crate::cbor::encode_snapshot(schema, &indices, &doc_blobs)?;
+        std::fs::write(out.as_ref(), bytes)
+            .map_err(|e| Error::InternalError(format!("failed to write snapshot file: {e}")))?;
+        Ok(())
+    }
+
+    /// Rebuild a collection at `path` from a snapshot file previously
+    /// written by [`Self::export_snapshot`].
+    ///
+    /// Recreates the schema via [`Self::create_and_open`], inserts every
+    /// exported document, then replays each exported field's [`IndexSpec`]
+    /// through [`Self::create_index`]. Errors on a snapshot `version` this
+    /// build doesn't recognize.
+    #[cfg(feature = "cbor")]
+    pub fn import_snapshot<P: AsRef<Path>>(path: P, snapshot: P) -> Result<Collection> {
+        let bytes = std::fs::read(snapshot.as_ref())
+            .map_err(|e| Error::InternalError(format!("failed to read snapshot file: {e}")))?;
+        let decoded = crate::cbor::decode_snapshot(&bytes)?;
+
+        let collection = Collection::create_and_open(path, decoded.schema)?;
+
+        if !decoded.docs.is_empty() {
+            let docs = decoded
+                .docs
+                .into_iter()
+                .map(|bytes| Doc::from_cbor(&bytes))
+                .collect::<Result<Vec<Doc>>>()?;
+            collection.insert(&docs)?;
+        }
+
+        for (field, spec) in decoded.indices {
+            collection.create_index(&field, spec.to_index_params())?;
+        }
+
+        Ok(collection)
+    }
+}
+
+/// Options for [`Collection::export_snapshot`].
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExportOptions {
+    /// Skip documents, writing only the schema and index specs — useful as
+    /// a template for creating new, empty collections with the same shape.
+    pub schema_only: bool,
+}
+
+impl Drop for Collection {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_collection_destroy(self.ptr) };
+        }
+        if let Some(dir) = &self.ephemeral_dir {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+/// Parameters for creating an index on a vector field.
+///
+/// # Index Types
+///
+/// - **HNSW**: Fast approximate search using hierarchical navigable small world graphs
+/// - **IVF**: Inverted file index, good for large datasets
+/// - **FLAT**: Brute force search, exact results
+/// - **INVERT**: Inverted index for scalar fields
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::{IndexParams, MetricType, QuantizeType};
+///
+/// // HNSW index with L2 distance
+/// let params = IndexParams::hnsw(16, 200, MetricType::L2, QuantizeType::Undefined);
+///
+/// // Flat index with cosine similarity
+/// let params = IndexParams::flat(MetricType::Cosine, QuantizeType::Undefined);
+/// ```
+pub struct IndexParams {
+    ptr: *mut ffi::zvec_index_params_t,
+    kind: IndexSpec,
+}
+
+/// The construction arguments an [`IndexParams`] was built with, captured at
+/// construction time since `IndexParams` itself only exposes
+/// [`IndexParams::index_type`] once built — there is no FFI call to read
+/// `m`/`ef_construction`/`n_list`/etc. back off the native handle.
+///
+/// [`Collection`] records one of these per field in [`Collection::create_index`],
+/// so [`Collection::export_snapshot`] can serialize it and
+/// [`Collection::import_snapshot`] can replay [`Self::to_index_params`] to
+/// rebuild the index elsewhere.
+#[derive(Debug, Clone, Copy)]
+pub enum IndexSpec {
+    Hnsw {
+        m: i32,
+        ef_construction: i32,
+        metric: MetricType,
+        quantize: QuantizeType,
+    },
+    Ivf {
+        n_list: i32,
+        n_iters: i32,
+        use_soar: bool,
+        metric: MetricType,
+        quantize: QuantizeType,
+    },
+    Flat {
+        metric: MetricType,
+        quantize: QuantizeType,
+    },
+    Invert {
+        enable_range_optimization: bool,
+    },
+}
+
+impl IndexSpec {
+    /// Rebuild the [`IndexParams`] this spec was captured from.
+    pub fn to_index_params(&self) -> IndexParams {
+        match *self {
+            IndexSpec::Hnsw {
+                m,
+                ef_construction,
+                metric,
+                quantize,
+            } => IndexParams::hnsw(m, ef_construction, metric, quantize),
+            IndexSpec::Ivf {
+                n_list,
+                n_iters,
+                use_soar,
+                metric,
+                quantize,
+            } => IndexParams::ivf(n_list, n_iters, use_soar, metric, quantize),
+            IndexSpec::Flat { metric, quantize } => IndexParams::flat(metric, quantize),
+            IndexSpec::Invert {
+                enable_range_optimization,
+            } => IndexParams::invert(enable_range_optimization),
+        }
+    }
+}
+
+impl IndexParams {
+    /// Create HNSW index parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - Number of connections per node (typically 8-64)
+    /// * `ef_construction` - Size of dynamic candidate list during construction (typically 100-400)
+    /// * `metric` - Distance metric (L2, Cosine, etc.)
+    /// * `quantize` - Quantization type for compression
+    pub fn hnsw(m: i32, ef_construction: i32, metric: MetricType, quantize: QuantizeType) -> Self {
+        let ptr = unsafe {
+            ffi::zvec_index_params_new_hnsw(m, ef_construction, metric.into(), quantize.into())
+        };
+        Self {
+            ptr,
+            kind: IndexSpec::Hnsw {
+                m,
+                ef_construction,
+                metric,
+                quantize,
+            },
+        }
+    }
+
+    /// Create IVF index parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_list` - Number of clusters/inverted lists
+    /// * `n_iters` - Number of k-means iterations
+    /// * `use_soar` - Whether to use SOAR optimization
+    /// * `metric` - Distance metric
+    /// * `quantize` - Quantization type
+    pub fn ivf(
+        n_list: i32,
+        n_iters: i32,
+        use_soar: bool,
+        metric: MetricType,
+        quantize: QuantizeType,
+    ) -> Self {
+        let ptr = unsafe {
+            ffi::zvec_index_params_new_ivf(
+                n_list,
+                n_iters,
+                use_soar,
+                metric.into(),
+                quantize.into(),
+            )
+        };
+        Self {
+            ptr,
+            kind: IndexSpec::Ivf {
+                n_list,
+                n_iters,
+                use_soar,
+                metric,
+                quantize,
+            },
+        }
+    }
+
+    /// Create FLAT (brute force) index parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric` - Distance metric
+    /// * `quantize` - Quantization type
+    pub fn flat(metric: MetricType, quantize: QuantizeType) -> Self {
+        let ptr = unsafe { ffi::zvec_index_params_new_flat(metric.into(), quantize.into()) };
+        Self {
+            ptr,
+            kind: IndexSpec::Flat { metric, quantize },
+        }
+    }
+
+    /// Create inverted index parameters for scalar fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable_range_optimization` - Whether to optimize range queries
+    pub fn invert(enable_range_optimization: bool) -> Self {
+        let ptr = unsafe { ffi::zvec_index_params_new_invert(enable_range_optimization) };
+        Self {
+            ptr,
+            kind: IndexSpec::Invert {
+                enable_range_optimization,
+            },
+        }
+    }
+
+    /// Get the index type.
+    pub fn index_type(&self) -> IndexType {
+        unsafe { ffi::zvec_index_params_type(self.ptr).into() }
+    }
+
+    /// The construction arguments this was built from, for
+    /// [`Collection::create_index`]'s snapshot bookkeeping.
+    pub(crate) fn spec(&self) -> IndexSpec {
+        self.kind
+    }
+}
+
+impl Drop for IndexParams {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_index_params_free(self.ptr) };
+        }
+    }
+}
+
+// SAFETY: IndexParams exclusively owns its native handle and has no `Sync`
+// impl, so it can be handed off to run on a different thread (e.g. via
+// `spawn_blocking`) like any other owned value.
+unsafe impl Send for IndexParams {}
+
+/// Where a collection's data directory actually lives, set on
+/// [`CollectionOptions`] via [`CollectionOptions::backend`] and passed to
+/// [`Collection::create_and_open_with_options`]/[`Collection::open_with_options`].
+///
+/// `path` still has to be given to those calls either way: for
+/// [`Self::Local`] it's the filesystem directory as usual, and for
+/// [`Self::S3`] it's the key prefix's local cache/staging directory, since
+/// every call in this crate takes a path-like first argument.
+#[derive(Debug, Clone)]
+pub enum StorageBackend {
+    /// A local filesystem directory (the default).
+    Local,
+    /// An S3-compatible bucket, e.g. for a read-only replica or a cold
+    /// collection kept off local disk. Requires the linked zvec build to
+    /// have been compiled with object-storage support, or
+    /// [`CollectionOptions::backend`] returns [`Error::NotSupported`].
+    S3 {
+        bucket: String,
+        prefix: String,
+        endpoint: String,
+        region: String,
+    },
+}
+
+pub struct CollectionOptions {
+    ptr: *mut ffi::zvec_collection_options_t,
+}
+
+impl CollectionOptions {
+    pub fn new() -> Self {
+        let ptr = unsafe { ffi::zvec_collection_options_new() };
+        Self { ptr }
+    }
+
+    pub fn read_only(self, read_only: bool) -> Self {
+        unsafe { ffi::zvec_collection_options_set_read_only(self.ptr, read_only) };
+        self
+    }
+
+    pub fn enable_mmap(self, enable: bool) -> Self {
+        unsafe { ffi::zvec_collection_options_set_enable_mmap(self.ptr, enable) };
+        self
+    }
+
+    /// Select the storage backend the collection's data directory lives
+    /// behind; see [`StorageBackend`].
+    ///
+    /// Validates `bucket`/`endpoint`/`region` are non-empty for
+    /// [`StorageBackend::S3`] up front, rather than letting a malformed
+    /// config reach the native library as an obscure status code. Whether
+    /// the linked build actually has object-storage support at all can only
+    /// be known once the native setter call returns, so that check happens
+    /// here too (surfaced as [`Error::NotSupported`]) rather than being
+    /// deferred to [`Collection::create_and_open_with_options`]/
+    /// [`Collection::open_with_options`].
+    pub fn backend(self, backend: StorageBackend) -> Result<Self> {
+        let status = match &backend {
+            StorageBackend::Local => unsafe {
+                ffi::zvec_collection_options_set_storage_backend(
+                    self.ptr,
+                    ffi::zvec_storage_backend_t_ZVEC_STORAGE_BACKEND_LOCAL,
+                    ptr::null(),
+                    ptr::null(),
+                    ptr::null(),
+                    ptr::null(),
+                )
+            },
+            StorageBackend::S3 {
+                bucket,
+                prefix,
+                endpoint,
+                region,
+            } => {
+                if bucket.is_empty() || endpoint.is_empty() || region.is_empty() {
+                    return Err(Error::InvalidArgument(
+                        "StorageBackend::S3 requires non-empty bucket, endpoint, and region"
+                            .to_string(),
+                    ));
+                }
+                let bucket_c = CString::new(bucket.as_str()).unwrap();
+                let prefix_c = CString::new(prefix.as_str()).unwrap();
+                let endpoint_c = CString::new(endpoint.as_str()).unwrap();
+                let region_c = CString::new(region.as_str()).unwrap();
+                unsafe {
+                    ffi::zvec_collection_options_set_storage_backend(
+                        self.ptr,
+                        ffi::zvec_storage_backend_t_ZVEC_STORAGE_BACKEND_S3,
+                        bucket_c.as_ptr(),
+                        prefix_c.as_ptr(),
+                        endpoint_c.as_ptr(),
+                        region_c.as_ptr(),
+                    )
+                }
+            }
+        };
+        check_status(status)?;
+        Ok(self)
+    }
+}
+
+impl Default for CollectionOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for CollectionOptions {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_collection_options_free(self.ptr) };
+        }
+    }
+}
+
+// SAFETY: Collection wraps a raw pointer to zvec C++ object.
+// The underlying zvec library uses internal mutexes (schema_handle_mtx_, write_mtx_)
+// for thread safety. Query operations are const and thread-safe.
+// This impl allows Collection to be sent between threads and wrapped in Arc<RwLock>.
+unsafe impl Send for Collection {}
+
+// SAFETY: Collection is safe to share between threads because:
+// 1. The underlying zvec C++ object uses internal mutexes for thread safety
+// 2. Query operations (const methods) are thread-safe by design
+// 3. Write operations use internal locking (write_mtx_)
+unsafe impl Sync for Collection {}
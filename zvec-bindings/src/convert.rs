@@ -0,0 +1,189 @@
+//! Typed value coercion for building [`Doc`](crate::doc::Doc)s from raw
+//! string input (CSV rows, log lines, JSON string fields) without each
+//! caller hand-parsing every column into its target [`DataType`](crate::types::DataType).
+//!
+//! A [`Conversion`] names a target type (and, for timestamps, a layout);
+//! [`Conversion::convert`] turns one raw string into a [`FieldValue`], which
+//! [`Doc::set_converted`](crate::doc::Doc::set_converted) then applies to a
+//! document field.
+
+use crate::error::{Error, Result};
+
+/// A value parsed by [`Conversion::convert`], ready to apply to a
+/// [`Doc`](crate::doc::Doc) field via
+/// [`Doc::set_converted`](crate::doc::Doc::set_converted).
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    Int64(i64),
+    Double(f64),
+    String(String),
+    /// Unix epoch seconds, produced by the `"timestamp"` and
+    /// `"timestamp_fmt:<strftime>"` conversions.
+    Timestamp(i64),
+}
+
+/// Names a target type to parse raw string input into, resolved from a
+/// short name via [`Conversion::from_name`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conversion {
+    Int,
+    Float,
+    Bool,
+    String,
+    /// RFC 3339 timestamp (e.g. `2024-01-15T09:30:00Z`).
+    Timestamp,
+    /// A timestamp in a custom layout, given as a restricted strftime
+    /// string supporting `%Y`, `%m`, `%d`, `%H`, `%M`, and `%S` plus
+    /// literal separators (e.g. `"%Y/%m/%d %H:%M:%S"`).
+    TimestampFmt(String),
+}
+
+impl Conversion {
+    /// Resolve a type name into a [`Conversion`]: `"int"`/`"integer"`,
+    /// `"float"`, `"bool"`/`"boolean"`, `"string"`/`"bytes"` (as-is),
+    /// `"timestamp"`, or `"timestamp_fmt:<strftime>"`.
+    ///
+    /// Errors with [`Error::InvalidArgument`] for any other name.
+    pub fn from_name(name: &str) -> Result<Self> {
+        if let Some(fmt) = name.strip_prefix("timestamp_fmt:") {
+            return Ok(Conversion::TimestampFmt(fmt.to_string()));
+        }
+        match name {
+            "int" | "integer" => Ok(Conversion::Int),
+            "float" => Ok(Conversion::Float),
+            "bool" | "boolean" => Ok(Conversion::Bool),
+            "string" | "bytes" => Ok(Conversion::String),
+            "timestamp" => Ok(Conversion::Timestamp),
+            other => Err(Error::InvalidArgument(format!(
+                "unknown conversion '{other}'"
+            ))),
+        }
+    }
+
+    /// Parse `raw` into a [`FieldValue`] according to this conversion.
+    pub fn convert(&self, raw: &str) -> Result<FieldValue> {
+        let raw = raw.trim();
+        match self {
+            Conversion::Int => raw
+                .parse::<i64>()
+                .map(FieldValue::Int64)
+                .map_err(|e| Error::InvalidArgument(format!("'{raw}' is not a valid int: {e}"))),
+            Conversion::Float => raw
+                .parse::<f64>()
+                .map(FieldValue::Double)
+                .map_err(|e| Error::InvalidArgument(format!("'{raw}' is not a valid float: {e}"))),
+            Conversion::Bool => match raw.to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" => Ok(FieldValue::Bool(true)),
+                "false" | "0" | "no" => Ok(FieldValue::Bool(false)),
+                other => Err(Error::InvalidArgument(format!(
+                    "'{other}' is not a valid bool"
+                ))),
+            },
+            Conversion::String => Ok(FieldValue::String(raw.to_string())),
+            Conversion::Timestamp => parse_rfc3339(raw).map(FieldValue::Timestamp),
+            Conversion::TimestampFmt(fmt) => parse_with_format(raw, fmt).map(FieldValue::Timestamp),
+        }
+    }
+}
+
+/// Parse `YYYY-MM-DD(T|' ')HH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)` into Unix
+/// epoch seconds. Only the subset of RFC 3339 ingestion pipelines actually
+/// emit is supported; there is no general calendar library backing this.
+fn parse_rfc3339(raw: &str) -> Result<i64> {
+    if raw.as_bytes().get(10) == Some(&b' ') {
+        parse_with_format(raw, "%Y-%m-%d %H:%M:%S")
+    } else {
+        parse_with_format(raw, "%Y-%m-%dT%H:%M:%S")
+    }
+}
+
+/// Parse `raw` against a restricted strftime-style `fmt` (`%Y %m %d %H %M
+/// %S`, any other character matched literally) into Unix epoch seconds.
+/// Trailing fractional seconds and a `Z`/`+HH:MM`/`-HH:MM` offset after the
+/// matched prefix are accepted and, for an explicit offset, applied.
+fn parse_with_format(raw: &str, fmt: &str) -> Result<i64> {
+    let bytes = raw.as_bytes();
+    let mut pos = 0usize;
+    let mut year = 1970i64;
+    let mut month = 1i64;
+    let mut day = 1i64;
+    let mut hour = 0i64;
+    let mut minute = 0i64;
+    let mut second = 0i64;
+
+    let take_digits = |bytes: &[u8], pos: &mut usize, n: usize| -> Result<i64> {
+        let end = *pos + n;
+        let slice = bytes.get(*pos..end).ok_or_else(|| {
+            Error::InvalidArgument(format!("'{raw}' is too short for format '{fmt}'"))
+        })?;
+        let s = std::str::from_utf8(slice)
+            .map_err(|_| Error::InvalidArgument(format!("'{raw}' is not valid UTF-8")))?;
+        let value = s
+            .parse::<i64>()
+            .map_err(|e| Error::InvalidArgument(format!("'{s}' is not numeric: {e}")))?;
+        *pos = end;
+        Ok(value)
+    };
+
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            match chars.next() {
+                Some('Y') => year = take_digits(bytes, &mut pos, 4)?,
+                Some('m') => month = take_digits(bytes, &mut pos, 2)?,
+                Some('d') => day = take_digits(bytes, &mut pos, 2)?,
+                Some('H') => hour = take_digits(bytes, &mut pos, 2)?,
+                Some('M') => minute = take_digits(bytes, &mut pos, 2)?,
+                Some('S') => second = take_digits(bytes, &mut pos, 2)?,
+                Some(other) => {
+                    return Err(Error::InvalidArgument(format!(
+                        "unsupported format specifier '%{other}'"
+                    )))
+                }
+                None => {
+                    return Err(Error::InvalidArgument(
+                        "format string ends with a bare '%'".into(),
+                    ))
+                }
+            }
+        } else {
+            match bytes.get(pos) {
+                Some(&b) if b == c as u8 => pos += 1,
+                _ => {
+                    return Err(Error::InvalidArgument(format!(
+                        "'{raw}' does not match format '{fmt}'"
+                    )))
+                }
+            }
+        }
+    }
+
+    let mut offset_seconds = 0i64;
+    if let Some(rest) = raw.get(pos..) {
+        let rest = rest.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+        if let Some(tz) = rest.strip_prefix('+').or_else(|| rest.strip_prefix('-')) {
+            let sign = if rest.starts_with('-') { -1 } else { 1 };
+            let mut parts = tz.splitn(2, ':');
+            let hh: i64 = parts.next().unwrap_or("0").parse().unwrap_or(0);
+            let mm: i64 = parts.next().unwrap_or("0").parse().unwrap_or(0);
+            offset_seconds = sign * (hh * 3600 + mm * 60);
+        }
+    }
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset_seconds;
+    Ok(seconds)
+}
+
+/// Days since the Unix epoch (1970-01-01) for a civil (proleptic Gregorian)
+/// date, per Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
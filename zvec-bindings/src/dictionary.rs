@@ -0,0 +1,182 @@
+//! Front-coded dictionary encoding for high-cardinality, repetitive strings.
+//!
+//! Distinct strings are sorted, grouped into fixed-size blocks, and within a
+//! block each string after the first is stored as the length of its shared
+//! prefix with the previous string plus the literal suffix bytes. Prefix and
+//! suffix lengths are packed with vbyte (7 data bits per byte, high bit as a
+//! continuation flag) so small deltas cost a single byte. A block-offset
+//! array lets id→string lookups decode a single block, and string→id lookups
+//! binary-search the block heads before scanning within the matched block.
+//!
+//! This is a standalone codec a caller can run over their own strings; it is
+//! not invoked automatically for a field marked
+//! [`FieldSchema::dictionary_encoded`](crate::schema::FieldSchema::dictionary_encoded),
+//! since this build has no client-side storage path for field values to hook
+//! it into (see that method's doc comment).
+
+/// Number of strings grouped into a single front-coded block.
+const BLOCK_SIZE: usize = 8;
+
+/// A sorted, front-coded string dictionary assigning a stable `u32` id to
+/// each distinct string.
+#[derive(Debug, Clone, Default)]
+pub struct FrontCodedDictionary {
+    /// Concatenated vbyte-coded block payloads.
+    blocks: Vec<u8>,
+    /// Byte offset into `blocks` where each block starts.
+    block_offsets: Vec<usize>,
+    /// The first (verbatim) string of each block, used for binary search.
+    block_heads: Vec<String>,
+    len: usize,
+}
+
+impl FrontCodedDictionary {
+    /// Build a dictionary from an arbitrary set of strings, sorting and
+    /// deduplicating them first.
+    pub fn build(strings: &[String]) -> Self {
+        let mut sorted: Vec<&str> = strings.iter().map(String::as_str).collect();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        let mut dict = Self {
+            blocks: Vec::new(),
+            block_offsets: Vec::new(),
+            block_heads: Vec::new(),
+            len: sorted.len(),
+        };
+
+        for chunk in sorted.chunks(BLOCK_SIZE) {
+            dict.block_offsets.push(dict.blocks.len());
+            dict.block_heads.push(chunk[0].to_string());
+
+            write_vbyte(&mut dict.blocks, chunk[0].len() as u64);
+            dict.blocks.extend_from_slice(chunk[0].as_bytes());
+
+            let mut previous = chunk[0];
+            for &s in &chunk[1..] {
+                let shared = shared_prefix_len(previous, s);
+                let suffix = &s.as_bytes()[shared..];
+                write_vbyte(&mut dict.blocks, shared as u64);
+                write_vbyte(&mut dict.blocks, suffix.len() as u64);
+                dict.blocks.extend_from_slice(suffix);
+                previous = s;
+            }
+        }
+
+        dict
+    }
+
+    /// Number of distinct strings in the dictionary.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decode the string assigned to `id`, or `None` if out of range.
+    pub fn string_at(&self, id: u32) -> Option<String> {
+        let id = id as usize;
+        if id >= self.len {
+            return None;
+        }
+        let block_idx = id / BLOCK_SIZE;
+        let within_block = id % BLOCK_SIZE;
+        self.decode_block(block_idx).into_iter().nth(within_block)
+    }
+
+    /// Look up the id for `value`, or `None` if it is not present.
+    ///
+    /// Binary-searches the block heads, then linearly scans the decoded
+    /// block for an exact match.
+    pub fn id_for(&self, value: &str) -> Option<u32> {
+        if self.block_heads.is_empty() {
+            return None;
+        }
+
+        let block_idx = match self
+            .block_heads
+            .binary_search_by(|head| head.as_str().cmp(value))
+        {
+            Ok(idx) => idx,
+            Err(0) => return None,
+            Err(idx) => idx - 1,
+        };
+
+        let decoded = self.decode_block(block_idx);
+        decoded
+            .iter()
+            .position(|s| s == value)
+            .map(|pos| (block_idx * BLOCK_SIZE + pos) as u32)
+    }
+
+    fn decode_block(&self, block_idx: usize) -> Vec<String> {
+        let start = self.block_offsets[block_idx];
+        let end = self
+            .block_offsets
+            .get(block_idx + 1)
+            .copied()
+            .unwrap_or(self.blocks.len());
+        let bytes = &self.blocks[start..end];
+
+        let mut cursor = 0;
+        let mut strings = Vec::with_capacity(BLOCK_SIZE);
+
+        let head_len = read_vbyte(bytes, &mut cursor) as usize;
+        let head = String::from_utf8_lossy(&bytes[cursor..cursor + head_len]).into_owned();
+        cursor += head_len;
+        strings.push(head);
+
+        while cursor < bytes.len() {
+            let shared = read_vbyte(bytes, &mut cursor) as usize;
+            let suffix_len = read_vbyte(bytes, &mut cursor) as usize;
+            let suffix = &bytes[cursor..cursor + suffix_len];
+            cursor += suffix_len;
+
+            let previous = strings.last().unwrap();
+            let mut next = previous.as_bytes()[..shared].to_vec();
+            next.extend_from_slice(suffix);
+            strings.push(String::from_utf8_lossy(&next).into_owned());
+        }
+
+        strings
+    }
+}
+
+fn shared_prefix_len(a: &str, b: &str) -> usize {
+    a.as_bytes()
+        .iter()
+        .zip(b.as_bytes())
+        .take_while(|(x, y)| x == y)
+        .count()
+}
+
+fn write_vbyte(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+            out.push(byte);
+        } else {
+            out.push(byte);
+            break;
+        }
+    }
+}
+
+fn read_vbyte(bytes: &[u8], cursor: &mut usize) -> u64 {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = bytes[*cursor];
+        *cursor += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    value
+}
@@ -0,0 +1,41 @@
+//! Content-digest identity for cached embeddings.
+//!
+//! A [`Digest`] is a stable hash of an embedder input (the target field, the
+//! text, and a caller-chosen model/version tag), used by
+//! [`Collection`](crate::collection::Collection)'s embedding cache to skip
+//! re-embedding a document whose text hasn't changed since it was last
+//! indexed. The cache itself lives in memory for the life of an open
+//! `Collection`; there is no FFI hook to persist it alongside the collection
+//! on disk, so it starts cold again after a reopen.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A stable content hash identifying an embedder input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Digest(String);
+
+impl Digest {
+    /// Compute the digest for `field`'s `text` under `model_version` (an
+    /// arbitrary caller-chosen tag distinguishing embedder implementations
+    /// or versions sharing the same field; pass `""` if the embedder has no
+    /// notion of versioning).
+    pub fn compute(field: &str, text: &str, model_version: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        field.hash(&mut hasher);
+        text.hash(&mut hasher);
+        model_version.hash(&mut hasher);
+        Digest(format!("{:016x}", hasher.finish()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
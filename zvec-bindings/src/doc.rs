@@ -1,9 +1,19 @@
+use core::ptr;
+use std::collections::HashMap;
 use std::ffi::CString;
-use std::ptr;
 
 use crate::error::{check_status, Result};
 use crate::ffi;
 
+// `no_std` + `alloc` support (as requested for embedded/WASM hosts) would
+// need `CString`/`CStr` to move to `alloc::ffi`, `HashMap` to a hasher that
+// doesn't depend on `std::collections::RandomState` (e.g. a `BTreeMap` or a
+// vendored hasher), and a `std`/`no_std` feature split declared in a
+// manifest to gate them - none of which this workspace has today (there is
+// no Cargo.toml to add a `default = ["std"]` feature to), so this only
+// takes the one step that's a no-op either way: `ptr` now comes from `core`
+// rather than `std`, since nothing here actually needs the `std` version.
+
 /// A document in a collection.
 ///
 /// Documents contain a primary key and zero or more fields (scalar values,
@@ -22,13 +32,17 @@ use crate::ffi;
 /// ```
 pub struct Doc {
     pub(crate) ptr: *mut ffi::zvec_doc_t,
+    pending_text: HashMap<String, String>,
 }
 
 impl Doc {
     /// Create a new empty document.
     pub fn new() -> Self {
         let ptr = unsafe { ffi::zvec_doc_new() };
-        Self { ptr }
+        Self {
+            ptr,
+            pending_text: HashMap::new(),
+        }
     }
 
     /// Create a new document with the given primary key.
@@ -73,6 +87,12 @@ impl Doc {
         Ok(self)
     }
 
+    /// Stage `text` to be embedded into `field` and return self for chaining.
+    pub fn with_text(mut self, field: &str, text: &str) -> Self {
+        self.set_text(field, text);
+        self
+    }
+
     /// Set the primary key.
     pub fn set_pk(&mut self, pk: impl Into<String>) {
         let pk_c = CString::new(pk.into()).unwrap();
@@ -145,6 +165,42 @@ impl Doc {
         check_status(status)
     }
 
+    /// Apply a [`FieldValue`](crate::convert::FieldValue) produced by
+    /// [`crate::convert::Conversion::convert`] to `field`, dispatching to
+    /// the matching `set_*` method. [`FieldValue::Timestamp`]
+    /// (`crate::convert::FieldValue::Timestamp`) has no dedicated native
+    /// type, so it is stored as Unix epoch seconds via [`Self::set_int64`].
+    pub fn set_converted(&mut self, field: &str, value: crate::convert::FieldValue) -> Result<()> {
+        use crate::convert::FieldValue;
+        match value {
+            FieldValue::Bool(v) => self.set_bool(field, v),
+            FieldValue::Int64(v) => self.set_int64(field, v),
+            FieldValue::Double(v) => self.set_double(field, v),
+            FieldValue::String(v) => self.set_string(field, &v),
+            FieldValue::Timestamp(epoch_seconds) => self.set_int64(field, epoch_seconds),
+        }
+    }
+
+    /// Stage `text` to be embedded into `field` (a `VectorFp32` field) on the
+    /// next `insert`/`upsert`/`update`, using the [`Embedder`](crate::embed::Embedder)
+    /// registered for `field` via
+    /// [`Collection::set_embedder`](crate::collection::Collection::set_embedder).
+    ///
+    /// Unlike [`CollectionSchema::register_embedder`](crate::schema::CollectionSchema::register_embedder),
+    /// which maps a separate source text field onto a target vector field,
+    /// this stages the text directly against `field` itself with no extra
+    /// field in the schema. Setting a vector directly on `field` takes
+    /// precedence over staged text.
+    pub fn set_text(&mut self, field: &str, text: &str) {
+        self.pending_text
+            .insert(field.to_string(), text.to_string());
+    }
+
+    /// Text staged via [`Self::set_text`], not yet resolved to a vector.
+    pub(crate) fn pending_text(&self) -> &HashMap<String, String> {
+        &self.pending_text
+    }
+
     pub fn set_sparse_vector(
         &mut self,
         field: &str,
@@ -214,6 +270,17 @@ impl Doc {
         }
     }
 
+    pub fn get_double(&self, field: &str) -> Option<f64> {
+        let field_c = CString::new(field).unwrap();
+        let mut value: f64 = 0.0;
+        let found = unsafe { ffi::zvec_doc_get_double(self.ptr, field_c.as_ptr(), &mut value) };
+        if found {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
     pub fn get_string(&self, field: &str) -> Option<&str> {
         let field_c = CString::new(field).unwrap();
         let mut value: *const std::os::raw::c_char = ptr::null();
@@ -226,28 +293,66 @@ impl Doc {
     }
 
     pub fn get_vector(&self, field: &str) -> Option<Vec<f32>> {
+        let mut buf = Vec::new();
+        get_vector_into_raw(self.ptr, field, &mut buf).then_some(buf)
+    }
+
+    /// Fetch a dense vector field into a caller-supplied buffer, returning
+    /// `false` (and clearing `out`) if `field` has no vector set.
+    ///
+    /// Lets a caller reuse one buffer across a [`DocListIter`] instead of
+    /// allocating one per document; see [`Self::get_vector`] for a version
+    /// that returns a fresh `Vec`.
+    pub fn get_vector_into(&self, field: &str, out: &mut Vec<f32>) -> bool {
+        get_vector_into_raw(self.ptr, field, out)
+    }
+
+    /// Fetch a sparse vector field set via [`Self::set_sparse_vector`], as
+    /// parallel `(indices, values)` vectors.
+    ///
+    /// Like [`Self::get_vector`], this sizes its buffers exactly: a first
+    /// call with zero-capacity buffers returns the field's nnz count, then a
+    /// second call fills `indices`/`values` allocated to that size. Returns
+    /// `None` if `field` has no sparse vector set.
+    pub fn get_sparse_vector(&self, field: &str) -> Option<(Vec<u32>, Vec<f32>)> {
         let field_c = CString::new(field).unwrap();
-        let mut buf = vec![0.0f32; 4096];
-        let actual_len = unsafe {
-            ffi::zvec_doc_get_vector_fp32(self.ptr, field_c.as_ptr(), buf.as_mut_ptr(), buf.len())
+        let nnz = unsafe {
+            ffi::zvec_doc_get_sparse_vector_fp32(
+                self.ptr,
+                field_c.as_ptr(),
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+                0,
+            )
         };
-        if actual_len == 0 {
+        if nnz == 0 {
             return None;
         }
-        if actual_len > buf.len() {
-            buf.resize(actual_len, 0.0);
-            unsafe {
-                ffi::zvec_doc_get_vector_fp32(
-                    self.ptr,
-                    field_c.as_ptr(),
-                    buf.as_mut_ptr(),
-                    buf.len(),
-                )
-            };
-        } else {
-            buf.truncate(actual_len);
-        }
-        Some(buf)
+        let mut indices = vec![0u32; nnz];
+        let mut values = vec![0.0f32; nnz];
+        unsafe {
+            ffi::zvec_doc_get_sparse_vector_fp32(
+                self.ptr,
+                field_c.as_ptr(),
+                indices.as_mut_ptr(),
+                indices.len(),
+                values.as_mut_ptr(),
+                values.len(),
+            )
+        };
+        Some((indices, values))
+    }
+
+    /// Fetch a dense vector field as an [`ndarray::Array1<f32>`].
+    ///
+    /// The FFI call always copies into a caller-supplied buffer, so this is
+    /// `get_vector` with the result wrapped for `ndarray` consumers rather
+    /// than a true zero-copy view; it still avoids any copy beyond the one
+    /// the C API requires.
+    #[cfg(feature = "ndarray")]
+    pub fn vector_view(&self, field: &str) -> Option<ndarray::Array1<f32>> {
+        self.get_vector(field).map(ndarray::Array1::from_vec)
     }
 
     pub fn has(&self, field: &str) -> bool {
@@ -310,6 +415,15 @@ impl DocList {
             None
         }
     }
+
+    /// Stack `field` from every document into an `(n, dim)` matrix.
+    ///
+    /// Docs missing `field` contribute a zero row. Returns an empty `(0, 0)`
+    /// matrix if the list is empty.
+    #[cfg(feature = "ndarray")]
+    pub fn vectors_matrix(&self, field: &str) -> ndarray::Array2<f32> {
+        vectors_matrix(self.iter().map(|doc| doc.get_vector(field)))
+    }
 }
 
 impl<'a> IntoIterator for &'a DocList {
@@ -382,6 +496,28 @@ impl<'a> DocRef<'a> {
         }
     }
 
+    pub fn get_bool(&self, field: &str) -> Option<bool> {
+        let field_c = CString::new(field).unwrap();
+        let mut value: bool = false;
+        let found = unsafe { ffi::zvec_doc_get_bool(self.ptr, field_c.as_ptr(), &mut value) };
+        if found {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_double(&self, field: &str) -> Option<f64> {
+        let field_c = CString::new(field).unwrap();
+        let mut value: f64 = 0.0;
+        let found = unsafe { ffi::zvec_doc_get_double(self.ptr, field_c.as_ptr(), &mut value) };
+        if found {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
     pub fn get_float(&self, field: &str) -> Option<f32> {
         let field_c = CString::new(field).unwrap();
         let mut value: f32 = 0.0;
@@ -393,6 +529,17 @@ impl<'a> DocRef<'a> {
         }
     }
 
+    pub fn get_int32(&self, field: &str) -> Option<i32> {
+        let field_c = CString::new(field).unwrap();
+        let mut value: i32 = 0;
+        let found = unsafe { ffi::zvec_doc_get_int32(self.ptr, field_c.as_ptr(), &mut value) };
+        if found {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
     pub fn get_int64(&self, field: &str) -> Option<i64> {
         let field_c = CString::new(field).unwrap();
         let mut value: i64 = 0;
@@ -405,28 +552,66 @@ impl<'a> DocRef<'a> {
     }
 
     pub fn get_vector(&self, field: &str) -> Option<Vec<f32>> {
+        let mut buf = Vec::new();
+        get_vector_into_raw(self.ptr, field, &mut buf).then_some(buf)
+    }
+
+    /// Fetch a dense vector field into a caller-supplied buffer.
+    ///
+    /// See [`Doc::get_vector_into`] for the buffer-reuse rationale.
+    pub fn get_vector_into(&self, field: &str, out: &mut Vec<f32>) -> bool {
+        get_vector_into_raw(self.ptr, field, out)
+    }
+
+    pub fn has(&self, field: &str) -> bool {
+        let field_c = CString::new(field).unwrap();
+        unsafe { ffi::zvec_doc_has(self.ptr, field_c.as_ptr()) }
+    }
+
+    pub fn is_null(&self, field: &str) -> bool {
+        let field_c = CString::new(field).unwrap();
+        unsafe { ffi::zvec_doc_is_null(self.ptr, field_c.as_ptr()) }
+    }
+
+    /// Fetch a sparse vector field as parallel `(indices, values)` vectors.
+    ///
+    /// See [`Doc::get_sparse_vector`] for the two-call sizing pattern.
+    pub fn get_sparse_vector(&self, field: &str) -> Option<(Vec<u32>, Vec<f32>)> {
         let field_c = CString::new(field).unwrap();
-        let mut buf = vec![0.0f32; 4096];
-        let actual_len = unsafe {
-            ffi::zvec_doc_get_vector_fp32(self.ptr, field_c.as_ptr(), buf.as_mut_ptr(), buf.len())
+        let nnz = unsafe {
+            ffi::zvec_doc_get_sparse_vector_fp32(
+                self.ptr,
+                field_c.as_ptr(),
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+                0,
+            )
         };
-        if actual_len == 0 {
+        if nnz == 0 {
             return None;
         }
-        if actual_len > buf.len() {
-            buf.resize(actual_len, 0.0);
-            unsafe {
-                ffi::zvec_doc_get_vector_fp32(
-                    self.ptr,
-                    field_c.as_ptr(),
-                    buf.as_mut_ptr(),
-                    buf.len(),
-                )
-            };
-        } else {
-            buf.truncate(actual_len);
-        }
-        Some(buf)
+        let mut indices = vec![0u32; nnz];
+        let mut values = vec![0.0f32; nnz];
+        unsafe {
+            ffi::zvec_doc_get_sparse_vector_fp32(
+                self.ptr,
+                field_c.as_ptr(),
+                indices.as_mut_ptr(),
+                indices.len(),
+                values.as_mut_ptr(),
+                values.len(),
+            )
+        };
+        Some((indices, values))
+    }
+
+    /// Fetch a dense vector field as an [`ndarray::Array1<f32>`].
+    ///
+    /// See [`Doc::vector_view`] for the copy semantics.
+    #[cfg(feature = "ndarray")]
+    pub fn vector_view(&self, field: &str) -> Option<ndarray::Array1<f32>> {
+        self.get_vector(field).map(ndarray::Array1::from_vec)
     }
 }
 
@@ -505,6 +690,55 @@ impl DocMap {
         }
         keys
     }
+
+    /// Stack `field` from every document into an `(n, dim)` matrix, in
+    /// [`DocMap::keys`] order.
+    ///
+    /// Docs missing `field` contribute a zero row. Returns an empty `(0, 0)`
+    /// matrix if the map is empty.
+    #[cfg(feature = "ndarray")]
+    pub fn vectors_matrix(&self, field: &str) -> ndarray::Array2<f32> {
+        vectors_matrix(
+            self.keys()
+                .into_iter()
+                .map(|key| self.get(key).and_then(|doc| doc.get_vector(field))),
+        )
+    }
+}
+
+/// Shared `get_vector`/`get_vector_into` logic for [`Doc`] and [`DocRef`]:
+/// queries the field's length up front via `zvec_doc_get_vector_len` and
+/// fetches into `out` in a single call, instead of guessing a buffer size
+/// and sometimes re-fetching.
+fn get_vector_into_raw(ptr: *mut ffi::zvec_doc_t, field: &str, out: &mut Vec<f32>) -> bool {
+    let field_c = CString::new(field).unwrap();
+    let len = unsafe { ffi::zvec_doc_get_vector_len(ptr, field_c.as_ptr()) };
+    if len == 0 {
+        out.clear();
+        return false;
+    }
+    out.resize(len, 0.0);
+    unsafe { ffi::zvec_doc_get_vector_fp32(ptr, field_c.as_ptr(), out.as_mut_ptr(), out.len()) };
+    true
+}
+
+/// Shared stacking logic for [`DocList::vectors_matrix`] and
+/// [`DocMap::vectors_matrix`]: rows are zero-filled for missing vectors, and
+/// the matrix width is taken from the first vector found.
+#[cfg(feature = "ndarray")]
+fn vectors_matrix(rows: impl Iterator<Item = Option<Vec<f32>>>) -> ndarray::Array2<f32> {
+    let rows: Vec<Option<Vec<f32>>> = rows.collect();
+    let dim = rows.iter().flatten().map(|v| v.len()).next().unwrap_or(0);
+    let mut flat = Vec::with_capacity(rows.len() * dim);
+    for row in &rows {
+        match row {
+            Some(v) if v.len() == dim => flat.extend_from_slice(v),
+            _ => flat.extend(std::iter::repeat(0.0f32).take(dim)),
+        }
+    }
+    ndarray::Array2::from_shape_vec((rows.len(), dim), flat).unwrap_or_else(|_| {
+        ndarray::Array2::from_shape_vec((0, 0), Vec::new()).expect("empty matrix is always valid")
+    })
 }
 
 impl Drop for DocMap {
@@ -518,3 +752,9 @@ impl Drop for DocMap {
 unsafe impl Send for DocList {}
 unsafe impl Send for DocMap {}
 unsafe impl Send for WriteResults {}
+
+// SAFETY: DocList exposes only read-only accessors (`get`, `iter`, `len`)
+// over data fixed at construction time, so shared references can be read
+// concurrently from multiple threads - e.g. via `Arc<DocList>` in
+// [`crate::query_cache::QueryCache`].
+unsafe impl Sync for DocList {}
@@ -0,0 +1,77 @@
+//! Pluggable auto-embedding of text fields into dense vector fields.
+//!
+//! An [`Embedder`] maps source text to `VectorFp32` embeddings. Register one
+//! on a [`Collection`](crate::collection::Collection) via
+//! [`Collection::set_embedder`](crate::collection::Collection::set_embedder)
+//! after declaring the field mapping with
+//! [`CollectionSchema::register_embedder`](crate::schema::CollectionSchema::register_embedder),
+//! and `insert`/`upsert` auto-populate the target vector field from the
+//! source text field whenever the vector is absent. [`VectorQuery::text`]
+//! embeds a query string the same way.
+
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+
+/// Maps batches of text to dense (and optionally sparse) embeddings.
+///
+/// Implementations should batch internally where possible: `insert`/`upsert`
+/// call `embed` once per batch of documents sharing a target field, not once
+/// per document.
+pub trait Embedder: Send + Sync {
+    /// Embed `texts`, returning one vector per input in the same order.
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>>;
+
+    /// Embed `texts` into sparse vectors, one `(indices, values)` pair per
+    /// input in the same order, for auto-embedding a
+    /// `SparseVectorFp32`/`SparseVectorFp16` target field.
+    ///
+    /// Unimplemented by default - most embedders only produce dense vectors -
+    /// so auto-embedding a sparse field fails with [`Error::NotSupported`]
+    /// until an implementation overrides this.
+    fn embed_sparse(&self, texts: &[&str]) -> Result<Vec<(Vec<u32>, Vec<f32>)>> {
+        let _ = texts;
+        Err(Error::NotSupported(
+            "this embedder does not support sparse embeddings".into(),
+        ))
+    }
+}
+
+/// A mapping from a source text field to a target `VectorFp32` field,
+/// recorded on a [`CollectionSchema`](crate::schema::CollectionSchema) so a
+/// reopened collection knows which embedder to re-apply where.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbedderMapping {
+    pub source_field: String,
+    pub target_field: String,
+    /// Dimension of `target_field`, read from the schema at registration
+    /// time so the [`IdentityEmbedder`] fallback can size its output
+    /// without a real embedder configured.
+    pub target_dimension: u32,
+}
+
+/// A no-op [`Embedder`] that returns a fixed-dimension zero vector for every
+/// input.
+///
+/// Useful as a placeholder while wiring up the text -> vector field mapping
+/// before a real embedding model is registered, and as a default so
+/// `insert` never panics on an unconfigured mapping.
+#[derive(Debug, Clone)]
+pub struct IdentityEmbedder {
+    dimension: usize,
+}
+
+impl IdentityEmbedder {
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+}
+
+impl Embedder for IdentityEmbedder {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|_| vec![0.0f32; self.dimension]).collect())
+    }
+}
+
+pub(crate) type SharedEmbedder = Arc<dyn Embedder>;
@@ -0,0 +1,95 @@
+//! Token-budgeted batching and retry for embedder calls.
+//!
+//! `insert`/`upsert`/`update` call into an [`Embedder`](crate::embed::Embedder)
+//! synchronously within the current call, so there is no cross-call queue
+//! to drain the way a server-side batching layer would; what
+//! [`EmbeddingsQueueConfig`] gives instead is sizing and resilience within
+//! one batch: it caps how much text goes into a single `embed` call
+//! (approximating a token budget) by splitting a large batch into several,
+//! and retries a failed sub-batch with exponential backoff + jitter,
+//! honoring a server-provided delay from [`Error::RateLimited`] when one is
+//! given.
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+/// Caps on how [`Collection`](crate::collection::Collection) batches text
+/// into [`Embedder::embed`](crate::embed::Embedder::embed) calls.
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddingsQueueConfig {
+    /// Maximum total length, in UTF-8 bytes, of text embedded in a single
+    /// `embed` call; a rough proxy for a token budget absent a real tokenizer.
+    pub max_batch_chars: usize,
+    /// Maximum number of retries for a sub-batch after
+    /// [`Error::RateLimited`].
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries, doubled each
+    /// attempt and given a small jitter.
+    pub base_backoff: Duration,
+}
+
+impl Default for EmbeddingsQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_chars: 32_000,
+            max_retries: 5,
+            base_backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+impl EmbeddingsQueueConfig {
+    /// Split `texts` into consecutive batch sizes whose combined byte
+    /// length does not exceed [`Self::max_batch_chars`]; a single text that
+    /// alone exceeds the budget still gets its own one-item batch rather
+    /// than being split mid-string.
+    pub(crate) fn chunk_sizes(&self, texts: &[&str]) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        let mut current = 0usize;
+        let mut current_len = 0usize;
+        for text in texts {
+            if current > 0 && current_len + text.len() > self.max_batch_chars {
+                sizes.push(current);
+                current = 0;
+                current_len = 0;
+            }
+            current += 1;
+            current_len += text.len();
+        }
+        if current > 0 {
+            sizes.push(current);
+        }
+        sizes
+    }
+
+    /// Run `call` for one sub-batch, retrying on [`Error::RateLimited`] with
+    /// exponential backoff + jitter (or the server-provided delay, if any)
+    /// up to [`Self::max_retries`] times.
+    pub(crate) fn with_retry<T>(&self, mut call: impl FnMut() -> Result<T>) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            match call() {
+                Ok(value) => return Ok(value),
+                Err(Error::RateLimited { retry_after_ms }) if attempt < self.max_retries => {
+                    let delay = retry_after_ms
+                        .map(Duration::from_millis)
+                        .unwrap_or_else(|| {
+                            self.base_backoff * 2u32.pow(attempt)
+                                + Duration::from_millis(jitter_ms(attempt))
+                        });
+                    sleep(delay);
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// A small deterministic jitter so retries from concurrent callers don't
+/// all wake up at once, without pulling in a random-number crate.
+fn jitter_ms(attempt: u32) -> u64 {
+    (u64::from(attempt).wrapping_mul(2_654_435_761) % 50) + 1
+}
@@ -0,0 +1,113 @@
+//! A concrete [`Embedder`] backed by a remote HTTP embedding service.
+//!
+//! Gate this module behind the `http-embed` cargo feature. [`HttpEmbedder`]
+//! POSTs a batch of texts as JSON and expects back one vector per input in
+//! the same order, so it can sit behind
+//! [`Collection::set_embedder`](crate::collection::Collection::set_embedder)
+//! wherever a schema wires up [`CollectionSchema::register_embedder`](crate::schema::CollectionSchema::register_embedder)
+//! or a doc calls [`Doc::set_text`](crate::doc::Doc::set_text), without users
+//! having to hand-roll an HTTP client of their own.
+
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::embed::Embedder;
+use crate::error::{Error, Result};
+
+/// Embeds text by POSTing it to a remote HTTP endpoint.
+///
+/// Expects `endpoint` to accept `POST {"input": [...texts]}` and respond
+/// with `{"embeddings": [[f32; dim]; texts.len()]}`, in request order — the
+/// shape of a typical hosted or self-hosted embedding service once pointed
+/// at a path that returns bare vectors.
+///
+/// A `429 Too Many Requests` response is surfaced as
+/// [`Error::RateLimited`], with `retry_after_ms` parsed from a
+/// `Retry-After` header when the server sends one (seconds only - an HTTP
+/// date `Retry-After` is treated as absent), so
+/// [`EmbeddingsQueueConfig::with_retry`](crate::embed_queue::EmbeddingsQueueConfig::with_retry)'s
+/// backoff actually fires for this embedder instead of every non-2xx
+/// response falling through to a plain [`Error::InternalError`].
+pub struct HttpEmbedder {
+    endpoint: String,
+    client: Client,
+    bearer_token: Option<String>,
+}
+
+impl HttpEmbedder {
+    /// Create an embedder that POSTs to `endpoint` with no authentication.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            client: Client::new(),
+            bearer_token: None,
+        }
+    }
+
+    /// Send `Authorization: Bearer <token>` with every request.
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    input: &'a [&'a str],
+}
+
+#[derive(Deserialize)]
+struct EmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+impl Embedder for HttpEmbedder {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut request = self
+            .client
+            .post(&self.endpoint)
+            .json(&EmbedRequest { input: texts });
+        if let Some(token) = &self.bearer_token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request
+            .send()
+            .map_err(|e| Error::InternalError(format!("embedding request failed: {e}")))?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after_ms = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(|seconds| seconds * 1000);
+            return Err(Error::RateLimited { retry_after_ms });
+        }
+
+        if !response.status().is_success() {
+            return Err(Error::InternalError(format!(
+                "embedding endpoint returned status {}",
+                response.status()
+            )));
+        }
+
+        let parsed: EmbedResponse = response
+            .json()
+            .map_err(|e| Error::InternalError(format!("invalid embedding response: {e}")))?;
+
+        if parsed.embeddings.len() != texts.len() {
+            return Err(Error::InternalError(format!(
+                "embedding endpoint returned {} vectors for {} inputs",
+                parsed.embeddings.len(),
+                texts.len()
+            )));
+        }
+
+        Ok(parsed.embeddings)
+    }
+}
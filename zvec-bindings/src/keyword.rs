@@ -0,0 +1,101 @@
+//! A lightweight client-side inverted index for keyword/full-text search.
+//!
+//! There is no FFI binding for a native full-text index type, so the
+//! keyword leg of a [`HybridQuery`](crate::query::HybridQuery) is served
+//! entirely from this crate: [`Collection::create_text_index`](crate::collection::Collection::create_text_index)
+//! registers a field to track, and `insert`/`upsert`/`delete` keep the
+//! index in sync from then on. Ranking uses Okapi BM25 over whitespace/
+//! punctuation-delimited, lowercased tokens.
+
+use std::collections::HashMap;
+
+const BM25_K1: f32 = 1.2;
+const BM25_B: f32 = 0.75;
+
+/// Term -> (pk -> term frequency) postings plus per-doc lengths, enough to
+/// score BM25 without re-tokenizing the corpus on every search.
+#[derive(Debug, Default)]
+pub(crate) struct KeywordIndex {
+    postings: HashMap<String, HashMap<String, u32>>,
+    doc_lengths: HashMap<String, u32>,
+    total_doc_len: u64,
+}
+
+impl KeywordIndex {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// (Re-)index `text` under `pk`, replacing any previous entry for it.
+    pub(crate) fn index(&mut self, pk: &str, text: &str) {
+        self.remove(pk);
+
+        let tokens = tokenize(text);
+        self.total_doc_len += tokens.len() as u64;
+        self.doc_lengths.insert(pk.to_string(), tokens.len() as u32);
+
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        for token in tokens {
+            *counts.entry(token).or_insert(0) += 1;
+        }
+        for (term, count) in counts {
+            self.postings
+                .entry(term)
+                .or_default()
+                .insert(pk.to_string(), count);
+        }
+    }
+
+    /// Drop `pk` from every posting list and the doc-length table.
+    pub(crate) fn remove(&mut self, pk: &str) {
+        if let Some(len) = self.doc_lengths.remove(pk) {
+            self.total_doc_len = self.total_doc_len.saturating_sub(len as u64);
+        }
+        for postings in self.postings.values_mut() {
+            postings.remove(pk);
+        }
+    }
+
+    /// BM25-rank `query` against the indexed corpus, returning up to
+    /// `topk` `(pk, score)` pairs sorted by descending score.
+    pub(crate) fn search(&self, query: &str, topk: usize) -> Vec<(String, f32)> {
+        let n = self.doc_lengths.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let avg_len = (self.total_doc_len as f32 / n as f32).max(1.0);
+
+        let mut scores: HashMap<String, f32> = HashMap::new();
+        for term in tokenize(query) {
+            let Some(postings) = self.postings.get(&term) else {
+                continue;
+            };
+            let df = postings.len() as f32;
+            let idf = ((n as f32 - df + 0.5) / (df + 0.5) + 1.0).ln();
+            for (pk, &tf) in postings {
+                let doc_len = *self.doc_lengths.get(pk).unwrap_or(&1) as f32;
+                let tf = tf as f32;
+                let denom = tf + BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / avg_len);
+                *scores.entry(pk.clone()).or_insert(0.0) += idf * (tf * (BM25_K1 + 1.0)) / denom;
+            }
+        }
+
+        let mut ranked: Vec<(String, f32)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        ranked.truncate(topk);
+        ranked
+    }
+}
+
+/// Lowercase, alphanumeric-run tokenization shared by indexing and search
+/// so both sides split text identically.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
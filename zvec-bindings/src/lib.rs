@@ -10,7 +10,30 @@
 //! - Support for dense and sparse vectors
 //! - HNSW, IVF, and FLAT index types
 //! - Static linking for easy deployment
-//! - Optional thread-safe [`SharedCollection`] via `sync` feature
+//! - Pluggable [`Embedder`] for auto-embedding text fields on insert and query, with an optional sparse-vector embedding path
+//! - Content-[`Digest`] embedding cache so re-indexing unchanged text skips the embedder
+//! - Token-budgeted embedder batching with retry/backoff via [`EmbeddingsQueueConfig`]
+//! - Named [`SnapshotId`] captures, with [`VectorQuery::require_snapshot_exists`] to fail queries against a deleted one
+//! - Optional thread-safe [`SharedCollection`] via `sync` feature, with an opt-in debounced background auto-optimize worker
+//! - Ephemeral [`Collection::in_memory`] / `create_shared_in_memory` collections with no durable filesystem path
+//! - Typed [`Conversion`] layer for coercing raw strings into [`Doc`] fields on ingestion
+//! - Client-side [`fuse_results`]/[`FusedResults`] Reciprocal Rank Fusion over independently-run [`DocList`](crate::doc::DocList)s, with per-list [`ScoreComponent`] breakdowns via [`FusedHitRef::score_details`]
+//! - [`WriteBatch`] for queuing a mixed sequence of inserts/upserts/updates/deletes, started with [`Collection::batch`] and submitted via one [`WriteBatch::commit`] call
+//! - [`Collection::bulk_insert`] for chunked ingestion of large document sets with per-document retry and progress reporting via [`BulkOptions`]
+//! - Optional columnar bulk import/export through Arrow/Parquet via `arrow` feature
+//! - Optional zero-allocation-beyond-FFI [`ndarray`](https://docs.rs/ndarray) vector views via `ndarray` feature
+//! - Optional non-blocking [`AsyncCollection`] surface over `tokio::task::spawn_blocking` via `async` feature
+//! - Optional conversion of fetch/query results into a `polars` `DataFrame` via `polars` feature
+//! - Optional self-describing CBOR encoding of [`Doc`] and [`CollectionSchema`] via `cbor` feature
+//! - Optional `Collection::export_snapshot`/`import_snapshot` portable backup/migration files via `cbor` feature
+//! - Pluggable [`StorageBackend`] (local disk or S3) via [`CollectionOptions::backend`], used with `Collection::create_and_open_with_options`/`open_with_options`
+//! - Optional [`HttpEmbedder`] for auto-embedding via a remote HTTP service, via `http-embed` feature
+//! - [`MultiVectorQuery`] for weighted RRF fusion of several dense-vector fields (e.g. title + body embeddings) via [`Collection::multi_vector_query`]
+//! - [`CollectionSchema::builder`] for declaring a whole schema in one chained expression
+//! - Arrow C Data Interface schema export/import (`FieldSchema::export_arrow`/`CollectionSchema::export_arrow`/`arrow::import_arrow`) via `arrow` feature
+//! - [`CollectionSchema::infer_from_samples`] to derive a schema from sample [`Record`]s instead of declaring fields by hand
+//! - `serde` `Serialize`/`Deserialize` for schema types, plus `CollectionSchema::to_json`/`from_json`/`to_string`/`from_string` via `serde` feature
+//! - [`CollectionSchema::diff`]/[`CollectionSchema::compatible_with`] for safe schema migrations
 //!
 //! ## Quick Start
 //!
@@ -109,26 +132,73 @@
 
 pub use zvec_sys as ffi;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod batch;
+#[cfg(feature = "cbor")]
+pub mod cbor;
 pub mod collection;
+pub mod convert;
+pub mod dictionary;
+pub mod digest;
 pub mod doc;
+pub mod embed;
+pub mod embed_queue;
 pub mod error;
+#[cfg(feature = "http-embed")]
+pub mod http_embed;
+mod keyword;
+#[cfg(feature = "polars")]
+pub mod polars;
 pub mod query;
+#[cfg(feature = "sync")]
+mod query_cache;
 pub mod schema;
+#[cfg(feature = "serde")]
+mod serde_schema;
+pub mod snapshot;
 pub mod types;
+pub mod vector_ops;
 
 #[cfg(feature = "sync")]
 pub mod sync;
 
+#[cfg(feature = "async")]
+pub use asynchronous::{AsyncCollection, SyncCollection};
+pub use batch::{BatchResults, BulkOptions, WriteBatch};
 pub use collection::Collection;
+#[cfg(feature = "cbor")]
+pub use collection::ExportOptions;
 pub use collection::IndexParams;
+pub use collection::IndexSpec;
+pub use collection::{CollectionOptions, StorageBackend};
+pub use convert::{Conversion, FieldValue};
+pub use dictionary::FrontCodedDictionary;
+pub use digest::Digest;
 pub use doc::Doc;
+pub use embed::{Embedder, EmbedderMapping, IdentityEmbedder};
+pub use embed_queue::EmbeddingsQueueConfig;
 pub use error::{Error, Result};
-pub use query::{GroupByVectorQuery, VectorQuery};
-pub use schema::{CollectionSchema, FieldSchema, VectorSchema};
+#[cfg(feature = "http-embed")]
+pub use http_embed::HttpEmbedder;
+pub use query::{
+    fuse_results, fuse_results_weighted, ExplainedGroupResults, ExplainedResults, FusedHitRef,
+    FusedResults, FusionMethod, GroupByVectorQuery, HybridQuery, HybridResults, MultiVectorQuery,
+    ScoreComponent, ScoreDetails, VectorQuery,
+};
+pub use schema::{
+    CollectionSchema, CollectionSchemaBuilder, Compatibility, FieldChange, FieldSchema, Record,
+    SampleValue, SchemaDiff, VectorSchema,
+};
+pub use snapshot::{SnapshotId, SnapshotInfo};
 pub use types::{DataType, IndexType, MetricType, QuantizeType};
 
 #[cfg(feature = "sync")]
-pub use sync::{create_and_open_shared, open_shared, SharedCollection};
+pub use sync::{
+    create_and_open_shared, create_shared_in_memory, open_shared, AutoIndexConfig, SharedCollection,
+};
 
 /// Create and open a new collection at the specified path.
 ///
@@ -185,6 +255,26 @@ pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Collection> {
     Collection::open(path)
 }
 
+/// Create a collection with no durable filesystem path (see
+/// [`Collection::in_memory`]).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::{create_in_memory, CollectionSchema, VectorSchema};
+///
+/// # fn main() -> zvec_bindings::Result<()> {
+/// let mut schema = CollectionSchema::new("my_collection");
+/// schema.add_field(VectorSchema::fp32("embedding", 128).into())?;
+///
+/// let collection = create_in_memory(schema)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_in_memory(schema: CollectionSchema) -> Result<Collection> {
+    Collection::in_memory(schema)
+}
+
 /// List all registered metric types.
 ///
 /// Returns the names of all metric types (distance functions) that are
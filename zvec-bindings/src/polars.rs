@@ -0,0 +1,105 @@
+//! Conversion of fetched/queried documents into [`polars::prelude::DataFrame`].
+//!
+//! One column is produced per schema field: scalar fields become typed
+//! `Series` (`Int64`, `Utf8`, `Boolean`, `Float64`), dense vector fields
+//! become a `List<Float32>` column, and a missing value on a document
+//! produces a Polars null in that row rather than a default.
+//!
+//! Gate this module behind the `polars` cargo feature.
+
+use polars::prelude::*;
+
+use crate::doc::{DocList, DocMap, DocRef};
+use crate::error::{Error, Result};
+use crate::schema::CollectionSchema;
+use crate::types::DataType;
+
+impl DocMap {
+    /// Convert every fetched document into a row of a [`DataFrame`], typed
+    /// according to `schema`.
+    ///
+    /// The primary key is included as a `pk` column; there is no score
+    /// column since fetched documents were not ranked.
+    pub fn into_dataframe(&self, schema: &CollectionSchema) -> Result<DataFrame> {
+        let keys = self.keys();
+        let pks: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        let docs: Vec<DocRef<'_>> = keys
+            .iter()
+            .map(|k| self.get(k).expect("key came from self.keys()"))
+            .collect();
+        build_dataframe(schema, pks, &docs, None)
+    }
+}
+
+impl DocList {
+    /// Convert every query hit into a row of a [`DataFrame`], typed
+    /// according to `schema`.
+    ///
+    /// Includes a `pk` column and a `score` column carrying each hit's
+    /// similarity score.
+    pub fn into_dataframe(&self, schema: &CollectionSchema) -> Result<DataFrame> {
+        let docs: Vec<DocRef<'_>> = self.iter().collect();
+        let pks: Vec<String> = docs.iter().map(|d| d.pk().to_string()).collect();
+        let scores: Vec<f32> = docs.iter().map(|d| d.score()).collect();
+        build_dataframe(schema, pks, &docs, Some(scores))
+    }
+}
+
+fn build_dataframe(
+    schema: &CollectionSchema,
+    pks: Vec<String>,
+    docs: &[DocRef<'_>],
+    scores: Option<Vec<f32>>,
+) -> Result<DataFrame> {
+    let mut columns = vec![Series::new("pk", pks)];
+    if let Some(scores) = scores {
+        columns.push(Series::new("score", scores));
+    }
+
+    for field in schema.fields() {
+        let name = field.name();
+        let series = match field.data_type() {
+            DataType::Bool => Series::new(
+                name,
+                docs.iter().map(|d| d.get_bool(name)).collect::<Vec<_>>(),
+            ),
+            DataType::Int32 => Series::new(
+                name,
+                docs.iter()
+                    .map(|d| d.get_int64(name).map(|v| v as i32))
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::Int64 => Series::new(
+                name,
+                docs.iter().map(|d| d.get_int64(name)).collect::<Vec<_>>(),
+            ),
+            DataType::Float => Series::new(
+                name,
+                docs.iter().map(|d| d.get_float(name)).collect::<Vec<_>>(),
+            ),
+            DataType::Double => Series::new(
+                name,
+                docs.iter().map(|d| d.get_double(name)).collect::<Vec<_>>(),
+            ),
+            DataType::String => Series::new(
+                name,
+                docs.iter()
+                    .map(|d| d.get_string(name).map(str::to_string))
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::VectorFp32 | DataType::VectorFp16 | DataType::VectorFp64 => Series::new(
+                name,
+                docs.iter().map(|d| d.get_vector(name)).collect::<Vec<_>>(),
+            ),
+            other => {
+                return Err(Error::NotSupported(format!(
+                    "{name}: no dataframe column mapping for {other:?}"
+                )))
+            }
+        };
+        columns.push(series);
+    }
+
+    DataFrame::new(columns)
+        .map_err(|e| Error::InternalError(format!("failed to build dataframe: {e}")))
+}
@@ -0,0 +1,1562 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::CString;
+
+use crate::doc::{DocList, DocMap, DocRef};
+use crate::error::{check_status, Result};
+use crate::ffi;
+use crate::snapshot::SnapshotId;
+use crate::types::MetricType;
+
+/// Why a result ranked where it did, opted into via `.explain(true)` on
+/// [`VectorQuery`]/[`GroupByVectorQuery`]/[`HybridQuery`] and retrieved
+/// through the matching `*_explained` method on
+/// [`Collection`](crate::collection::Collection).
+#[derive(Debug, Clone)]
+pub struct ScoreDetails {
+    /// The metric the caller recorded with `.metric(...)`, or
+    /// [`MetricType::Undefined`] if not set; the query doesn't otherwise
+    /// know which metric the field's index was built with.
+    pub metric: MetricType,
+    /// The score as returned by the index scan, before any client-side
+    /// normalization.
+    pub raw_score: f32,
+    /// `raw_score` min-max normalized to `[0, 1]` across the full result
+    /// set this detail belongs to.
+    pub normalized_score: f32,
+    /// 0-based position within the index scan's ranked output.
+    pub rank: usize,
+    /// Whether the originating query had a `.filter(...)` expression set.
+    /// `Some(true)` when one was set (every returned doc necessarily passed
+    /// it, since the native engine filters server-side before ranking);
+    /// `None` when the query had no filter, so the concept doesn't apply.
+    pub filter_matched: Option<bool>,
+    /// Per-leg contributions for a fused (hybrid) result; empty for a
+    /// plain [`VectorQuery`]/[`GroupByVectorQuery`] result.
+    pub components: Vec<ScoreComponent>,
+}
+
+/// One contributing sub-score behind a fused [`ScoreDetails`], e.g. the
+/// dense or sparse leg of a [`HybridQuery`].
+#[derive(Debug, Clone)]
+pub struct ScoreComponent {
+    /// Which leg this contribution came from (e.g. `"dense"`, `"sparse"`).
+    pub source: String,
+    /// That leg's own score for this result before fusion.
+    pub score: f32,
+    /// The weight [`HybridResults::fuse`] gave this leg when combining it
+    /// into the fused score.
+    pub weight: f32,
+}
+
+/// A vector similarity search query.
+///
+/// Use the builder pattern to construct queries:
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::VectorQuery;
+///
+/// let query = VectorQuery::new("embedding")
+///     .topk(10)
+///     .filter("category = 'electronics'")
+///     .vector(&[0.1, 0.2, 0.3, 0.4])?;
+/// # Ok::<(), zvec_bindings::Error>(())
+/// ```
+pub struct VectorQuery {
+    pub(crate) ptr: *mut ffi::zvec_vector_query_t,
+    field_name: String,
+    pending_text: Option<String>,
+    explain: bool,
+    metric: MetricType,
+    require_snapshot_exists: Option<SnapshotId>,
+    topk: Option<usize>,
+    offset: Option<usize>,
+    filter: Option<String>,
+    dense_vector: Option<Vec<f32>>,
+    sparse_vector: Option<(Vec<u32>, Vec<f32>)>,
+    search_params: BTreeMap<String, String>,
+}
+
+impl VectorQuery {
+    /// Create a new query for the specified vector field.
+    pub fn new(field_name: &str) -> Self {
+        let field_c = CString::new(field_name).unwrap();
+        let ptr = unsafe { ffi::zvec_vector_query_new(field_c.as_ptr()) };
+        Self {
+            ptr,
+            field_name: field_name.to_string(),
+            pending_text: None,
+            explain: false,
+            metric: MetricType::Undefined,
+            require_snapshot_exists: None,
+            topk: None,
+            offset: None,
+            filter: None,
+            dense_vector: None,
+            sparse_vector: None,
+            search_params: BTreeMap::new(),
+        }
+    }
+
+    /// Create a query against `field_name` from raw query text instead of a
+    /// precomputed vector.
+    ///
+    /// The text is embedded lazily with the field's registered
+    /// [`Embedder`](crate::embed::Embedder) when the query actually runs via
+    /// [`crate::collection::Collection::query`], using the same mapping set
+    /// up for auto-embedding on insert.
+    pub fn text(field_name: &str, text: &str) -> Self {
+        let mut query = Self::new(field_name);
+        query.pending_text = Some(text.to_string());
+        query
+    }
+
+    /// The vector field this query targets.
+    pub(crate) fn field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    /// The pending query text set via [`Self::text`], if any, as a borrow
+    /// suitable for a single-item `embed` batch.
+    pub(crate) fn pending_text(&self) -> Option<&str> {
+        self.pending_text.as_deref()
+    }
+
+    /// Opt into per-result [`ScoreDetails`], retrievable via
+    /// [`crate::collection::Collection::query_explained`] instead of the
+    /// plain [`crate::collection::Collection::query`].
+    pub fn explain(mut self, enable: bool) -> Self {
+        self.explain = enable;
+        self
+    }
+
+    /// Alias for [`Self::explain`] under the name ranking-explainability
+    /// callers may look for first.
+    pub fn with_score_details(self, enable: bool) -> Self {
+        self.explain(enable)
+    }
+
+    /// Record which [`MetricType`] this field's index uses, so
+    /// [`ScoreDetails::metric`] can report it. Purely descriptive: it is not
+    /// sent to the index and does not change how the query runs.
+    pub fn metric(mut self, metric: MetricType) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    pub(crate) fn is_explain(&self) -> bool {
+        self.explain
+    }
+
+    pub(crate) fn metric_type(&self) -> MetricType {
+        self.metric
+    }
+
+    /// Whether [`Self::filter`] was called, for [`ScoreDetails::filter_matched`].
+    pub(crate) fn has_filter(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    /// Fail the query with [`crate::error::Error::NotFound`] if `snapshot`
+    /// has since been deleted via [`crate::collection::Collection::delete_snapshot`].
+    ///
+    /// This is existence-validation only, not a point-in-time read: see
+    /// [`crate::snapshot`] for why this build has no segment-retention hook
+    /// to roll the query back to the data visible when `snapshot` was
+    /// captured. The query still runs against the live head either way.
+    pub fn require_snapshot_exists(mut self, snapshot: SnapshotId) -> Self {
+        self.require_snapshot_exists = Some(snapshot);
+        self
+    }
+
+    pub(crate) fn required_snapshot(&self) -> Option<SnapshotId> {
+        self.require_snapshot_exists
+    }
+
+    /// Set the number of results to return (default: 10).
+    pub fn topk(mut self, topk: usize) -> Self {
+        unsafe { ffi::zvec_vector_query_set_topk(self.ptr, topk as std::os::raw::c_int) };
+        self.topk = Some(topk);
+        self
+    }
+
+    /// Skip the first `offset` ranked hits server-side before returning the
+    /// next `topk` of them, for paging through a result set without
+    /// re-fetching and slicing a growing top-k client-side.
+    pub fn offset(mut self, offset: usize) -> Self {
+        unsafe { ffi::zvec_vector_query_set_offset(self.ptr, offset as std::os::raw::c_int) };
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Convenience for [`Self::offset`] + [`Self::topk`] expressed as a
+    /// 0-based page number and page size, e.g. `.page(1, 20)` is the second
+    /// page of 20 results (`offset(20).topk(20)`).
+    pub fn page(self, number: usize, size: usize) -> Self {
+        self.offset(number * size).topk(size)
+    }
+
+    /// Set a filter expression to narrow results.
+    pub fn filter(mut self, filter: &str) -> Self {
+        let filter_c = CString::new(filter).unwrap();
+        unsafe { ffi::zvec_vector_query_set_filter(self.ptr, filter_c.as_ptr()) };
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Escape hatch for passing an arbitrary `key=value` search-time
+    /// parameter through to the index, for knobs not otherwise exposed as a
+    /// dedicated builder method (e.g. a future index type's own tuning
+    /// parameters).
+    pub fn search_param(mut self, key: &str, value: &str) -> Self {
+        let key_c = CString::new(key).unwrap();
+        let value_c = CString::new(value).unwrap();
+        unsafe {
+            ffi::zvec_vector_query_set_search_param(self.ptr, key_c.as_ptr(), value_c.as_ptr())
+        };
+        self.search_params
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Widen or narrow the HNSW candidate list considered at query time,
+    /// trading recall for latency without rebuilding the index. Shorthand
+    /// for `.search_param("ef_search", ...)`.
+    pub fn ef_search(self, ef_search: usize) -> Self {
+        self.search_param("ef_search", &ef_search.to_string())
+    }
+
+    /// Whether to include vector values in results.
+    pub fn include_vector(self, include: bool) -> Self {
+        unsafe { ffi::zvec_vector_query_set_include_vector(self.ptr, include) };
+        self
+    }
+
+    /// Whether to include document IDs in results.
+    pub fn include_doc_id(self, include: bool) -> Self {
+        unsafe { ffi::zvec_vector_query_set_include_doc_id(self.ptr, include) };
+        self
+    }
+
+    /// Set which fields to include in results.
+    pub fn output_fields(self, fields: &[&str]) -> Self {
+        let fields_c: Vec<CString> = fields.iter().map(|f| CString::new(*f).unwrap()).collect();
+        let mut fields_ptr: Vec<*const std::os::raw::c_char> =
+            fields_c.iter().map(|f| f.as_ptr()).collect();
+        unsafe {
+            ffi::zvec_vector_query_set_output_fields(
+                self.ptr,
+                fields_ptr.as_mut_ptr(),
+                fields_ptr.len(),
+            )
+        };
+        self
+    }
+
+    /// Set the query vector for dense vectors.
+    pub fn vector(mut self, vector: &[f32]) -> Result<Self> {
+        let status = unsafe {
+            ffi::zvec_vector_query_set_vector_fp32(self.ptr, vector.as_ptr(), vector.len())
+        };
+        check_status(status)?;
+        self.dense_vector = Some(vector.to_vec());
+        Ok(self)
+    }
+
+    /// Set the query vector for sparse vectors.
+    pub fn sparse_vector(mut self, indices: &[u32], values: &[f32]) -> Result<Self> {
+        if indices.len() != values.len() {
+            return Err(crate::error::Error::InvalidArgument(
+                "indices and values must have same length".into(),
+            ));
+        }
+        let status = unsafe {
+            ffi::zvec_vector_query_set_sparse_vector_fp32(
+                self.ptr,
+                indices.as_ptr(),
+                indices.len(),
+                values.as_ptr(),
+                values.len(),
+            )
+        };
+        check_status(status)?;
+        self.sparse_vector = Some((indices.to_vec(), values.to_vec()));
+        Ok(self)
+    }
+
+    /// A normalized, hashable snapshot of this query's field, topk, filter,
+    /// and vector data, used by [`crate::sync::SharedCollection::with_cache`]
+    /// to key cached results. Two queries that would hit the index the same
+    /// way produce equal keys regardless of builder call order.
+    pub(crate) fn cache_key(&self) -> QueryCacheKey {
+        QueryCacheKey {
+            field_name: self.field_name.clone(),
+            topk: self.topk,
+            offset: self.offset,
+            filter: self.filter.clone(),
+            pending_text: self.pending_text.clone(),
+            dense_vector: self
+                .dense_vector
+                .as_ref()
+                .map(|v| v.iter().map(|f| f.to_bits()).collect()),
+            sparse_vector: self.sparse_vector.as_ref().map(|(indices, values)| {
+                (
+                    indices.clone(),
+                    values.iter().map(|f| f.to_bits()).collect(),
+                )
+            }),
+            metric: self.metric,
+            require_snapshot_exists: self.require_snapshot_exists,
+            group_by: None,
+            group_count: None,
+            search_params: self.search_params.clone(),
+        }
+    }
+}
+
+/// See [`VectorQuery::cache_key`] and [`GroupByVectorQuery::cache_key`].
+///
+/// Plain [`VectorQuery`] and [`GroupByVectorQuery`] caches are kept in
+/// separate maps (see [`crate::query_cache::QueryCache`]), so the unused
+/// `group_by`/`group_count` (for a [`VectorQuery`]) or `offset`/
+/// `sparse_vector`/`require_snapshot_exists` (for a [`GroupByVectorQuery`])
+/// fields never cause a collision between the two query kinds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct QueryCacheKey {
+    field_name: String,
+    topk: Option<usize>,
+    offset: Option<usize>,
+    filter: Option<String>,
+    pending_text: Option<String>,
+    dense_vector: Option<Vec<u32>>,
+    sparse_vector: Option<(Vec<u32>, Vec<u32>)>,
+    metric: MetricType,
+    require_snapshot_exists: Option<SnapshotId>,
+    group_by: Option<String>,
+    group_count: Option<u32>,
+    search_params: BTreeMap<String, String>,
+}
+
+impl Drop for VectorQuery {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_vector_query_free(self.ptr) };
+        }
+    }
+}
+
+// SAFETY: VectorQuery exclusively owns its native query handle (no other
+// pointer ever aliases it, and it has no `Sync` impl so it can't be built on
+// one thread while read on another) - it can be handed off to run on a
+// different thread, e.g. via `spawn_blocking`, just like any other owned value.
+unsafe impl Send for VectorQuery {}
+
+pub struct GroupByVectorQuery {
+    pub(crate) ptr: *mut ffi::zvec_group_by_vector_query_t,
+    field_name: String,
+    pending_text: Option<String>,
+    explain: bool,
+    metric: MetricType,
+    group_by: Option<String>,
+    group_count: Option<u32>,
+    group_topk: Option<u32>,
+    filter: Option<String>,
+    dense_vector: Option<Vec<f32>>,
+    search_params: BTreeMap<String, String>,
+}
+
+impl GroupByVectorQuery {
+    pub fn new(field_name: &str) -> Self {
+        let field_c = CString::new(field_name).unwrap();
+        let ptr = unsafe { ffi::zvec_group_by_vector_query_new(field_c.as_ptr()) };
+        Self {
+            ptr,
+            field_name: field_name.to_string(),
+            pending_text: None,
+            explain: false,
+            metric: MetricType::Undefined,
+            group_by: None,
+            group_count: None,
+            group_topk: None,
+            filter: None,
+            dense_vector: None,
+            search_params: BTreeMap::new(),
+        }
+    }
+
+    /// Create a grouped query against `field_name` from raw query text
+    /// instead of a precomputed vector, mirroring [`VectorQuery::text`].
+    ///
+    /// The text is embedded lazily with the field's registered
+    /// [`Embedder`](crate::embed::Embedder) when the query actually runs via
+    /// [`crate::collection::Collection::group_by_query`], using the same
+    /// mapping set up for auto-embedding on insert.
+    pub fn text(field_name: &str, text: &str) -> Self {
+        let mut query = Self::new(field_name);
+        query.pending_text = Some(text.to_string());
+        query
+    }
+
+    /// The vector field this query targets.
+    pub(crate) fn field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    /// The pending query text set via [`Self::text`], if any, as a borrow
+    /// suitable for a single-item `embed` batch.
+    pub(crate) fn pending_text(&self) -> Option<&str> {
+        self.pending_text.as_deref()
+    }
+
+    /// Opt into per-result [`ScoreDetails`], retrievable via
+    /// [`crate::collection::Collection::group_by_query_explained`] instead
+    /// of the plain [`crate::collection::Collection::group_by_query`].
+    pub fn explain(mut self, enable: bool) -> Self {
+        self.explain = enable;
+        self
+    }
+
+    /// Record which [`MetricType`] this field's index uses, so
+    /// [`ScoreDetails::metric`] can report it.
+    pub fn metric(mut self, metric: MetricType) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    pub(crate) fn is_explain(&self) -> bool {
+        self.explain
+    }
+
+    pub(crate) fn metric_type(&self) -> MetricType {
+        self.metric
+    }
+
+    /// Whether [`Self::filter`] was called, for [`ScoreDetails::filter_matched`].
+    pub(crate) fn has_filter(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    pub fn group_by(mut self, field_name: &str) -> Self {
+        let field_c = CString::new(field_name).unwrap();
+        unsafe { ffi::zvec_group_by_vector_query_set_group_by_field(self.ptr, field_c.as_ptr()) };
+        self.group_by = Some(field_name.to_string());
+        self
+    }
+
+    pub fn group_count(mut self, count: u32) -> Self {
+        unsafe { ffi::zvec_group_by_vector_query_set_group_count(self.ptr, count) };
+        self.group_count = Some(count);
+        self
+    }
+
+    pub fn group_topk(mut self, topk: u32) -> Self {
+        unsafe { ffi::zvec_group_by_vector_query_set_group_topk(self.ptr, topk) };
+        self.group_topk = Some(topk);
+        self
+    }
+
+    pub fn filter(mut self, filter: &str) -> Self {
+        let filter_c = CString::new(filter).unwrap();
+        unsafe { ffi::zvec_group_by_vector_query_set_filter(self.ptr, filter_c.as_ptr()) };
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Escape hatch for passing an arbitrary `key=value` search-time
+    /// parameter through to the index, mirroring [`VectorQuery::search_param`].
+    pub fn search_param(mut self, key: &str, value: &str) -> Self {
+        let key_c = CString::new(key).unwrap();
+        let value_c = CString::new(value).unwrap();
+        unsafe {
+            ffi::zvec_group_by_vector_query_set_search_param(
+                self.ptr,
+                key_c.as_ptr(),
+                value_c.as_ptr(),
+            )
+        };
+        self.search_params
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Widen or narrow the HNSW candidate list considered at query time,
+    /// mirroring [`VectorQuery::ef_search`].
+    pub fn ef_search(self, ef_search: usize) -> Self {
+        self.search_param("ef_search", &ef_search.to_string())
+    }
+
+    pub fn output_fields(self, fields: &[&str]) -> Self {
+        let fields_c: Vec<CString> = fields.iter().map(|f| CString::new(*f).unwrap()).collect();
+        let mut fields_ptr: Vec<*const std::os::raw::c_char> =
+            fields_c.iter().map(|f| f.as_ptr()).collect();
+        unsafe {
+            ffi::zvec_group_by_vector_query_set_output_fields(
+                self.ptr,
+                fields_ptr.as_mut_ptr(),
+                fields_ptr.len(),
+            )
+        };
+        self
+    }
+
+    pub fn vector(mut self, vector: &[f32]) -> Result<Self> {
+        let status = unsafe {
+            ffi::zvec_group_by_vector_query_set_vector_fp32(self.ptr, vector.as_ptr(), vector.len())
+        };
+        check_status(status)?;
+        self.dense_vector = Some(vector.to_vec());
+        Ok(self)
+    }
+
+    /// A normalized, hashable snapshot of this query's field, group-by
+    /// settings, filter, and vector data, used by
+    /// [`crate::sync::SharedCollection::with_cache`] to key cached results.
+    pub(crate) fn cache_key(&self) -> QueryCacheKey {
+        QueryCacheKey {
+            field_name: self.field_name.clone(),
+            topk: self.group_topk.map(|topk| topk as usize),
+            offset: None,
+            filter: self.filter.clone(),
+            pending_text: self.pending_text.clone(),
+            dense_vector: self
+                .dense_vector
+                .as_ref()
+                .map(|v| v.iter().map(|f| f.to_bits()).collect()),
+            sparse_vector: None,
+            metric: self.metric,
+            require_snapshot_exists: None,
+            group_by: self.group_by.clone(),
+            group_count: self.group_count,
+            search_params: self.search_params.clone(),
+        }
+    }
+}
+
+impl Drop for GroupByVectorQuery {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_group_by_vector_query_free(self.ptr) };
+        }
+    }
+}
+
+// SAFETY: see the `unsafe impl Send for VectorQuery` above - same
+// exclusive-ownership, no-`Sync` reasoning applies to this handle.
+unsafe impl Send for GroupByVectorQuery {}
+
+pub struct GroupResults {
+    pub(crate) inner: ffi::zvec_group_results_t,
+}
+
+impl GroupResults {
+    pub fn len(&self) -> usize {
+        self.inner.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.count == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<GroupResultRef<'_>> {
+        if index < self.inner.count {
+            Some(GroupResultRef {
+                inner: unsafe { &*self.inner.groups.add(index) },
+                _marker: std::marker::PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = GroupResultRef<'_>> + '_ {
+        (0..self.len()).filter_map(|i| self.get(i))
+    }
+}
+
+impl Drop for GroupResults {
+    fn drop(&mut self) {
+        unsafe { ffi::zvec_group_results_free(&mut self.inner) };
+    }
+}
+
+pub struct GroupResultRef<'a> {
+    inner: &'a ffi::zvec_group_result_t,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> GroupResultRef<'a> {
+    pub fn group_by_value(&self) -> &str {
+        unsafe {
+            if self.inner.group_by_value.is_null() {
+                ""
+            } else {
+                std::ffi::CStr::from_ptr(self.inner.group_by_value)
+                    .to_str()
+                    .unwrap_or("")
+            }
+        }
+    }
+
+    pub fn docs(&self) -> &crate::doc::DocList {
+        unsafe { std::mem::transmute(&self.inner.docs) }
+    }
+}
+
+// SAFETY: GroupResults owns its FFI data and can be safely sent between threads.
+unsafe impl Send for GroupResults {}
+
+// SAFETY: GroupResults exposes only read-only accessors over data fixed at
+// construction time, so shared references can be read concurrently from
+// multiple threads - e.g. via `Arc<GroupResults>` in
+// [`crate::query_cache::QueryCache`].
+unsafe impl Sync for GroupResults {}
+
+/// Build one [`ScoreDetails`] per doc in `list`'s existing rank order,
+/// min-max normalizing `DocRef::score` across the whole list.
+///
+/// Shared by [`crate::collection::Collection::query_explained`] and
+/// [`crate::collection::Collection::group_by_query_explained`] (per group).
+pub(crate) fn score_details_for_list(
+    list: &DocList,
+    metric: MetricType,
+    filter_matched: Option<bool>,
+) -> Vec<ScoreDetails> {
+    let raw_scores: Vec<f32> = list.iter().map(|d| d.score()).collect();
+    let min = raw_scores.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = raw_scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+
+    raw_scores
+        .into_iter()
+        .enumerate()
+        .map(|(rank, raw_score)| ScoreDetails {
+            metric,
+            raw_score,
+            normalized_score: (raw_score - min) / range,
+            rank,
+            filter_matched,
+            components: Vec::new(),
+        })
+        .collect()
+}
+
+/// The result of a [`VectorQuery`] run with `.explain(true)` via
+/// [`crate::collection::Collection::query_explained`]: the same ranked
+/// [`DocList`], paired with one [`ScoreDetails`] per doc.
+pub struct ExplainedResults {
+    docs: DocList,
+    details: Vec<ScoreDetails>,
+}
+
+impl ExplainedResults {
+    pub(crate) fn new(docs: DocList, metric: MetricType, filter_matched: Option<bool>) -> Self {
+        let details = score_details_for_list(&docs, metric, filter_matched);
+        Self { docs, details }
+    }
+
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<(DocRef<'_>, &ScoreDetails)> {
+        Some((self.docs.get(index)?, self.details.get(index)?))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (DocRef<'_>, &ScoreDetails)> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// The plain result list, without the paired [`ScoreDetails`].
+    pub fn docs(&self) -> &DocList {
+        &self.docs
+    }
+}
+
+/// The result of a [`GroupByVectorQuery`] run with `.explain(true)` via
+/// [`crate::collection::Collection::group_by_query_explained`]: the same
+/// [`GroupResults`], with each group's docs paired with a [`ScoreDetails`]
+/// normalized within that group.
+pub struct ExplainedGroupResults {
+    groups: GroupResults,
+    details: Vec<Vec<ScoreDetails>>,
+}
+
+impl ExplainedGroupResults {
+    pub(crate) fn new(
+        groups: GroupResults,
+        metric: MetricType,
+        filter_matched: Option<bool>,
+    ) -> Self {
+        let details = groups
+            .iter()
+            .map(|group| score_details_for_list(group.docs(), metric, filter_matched))
+            .collect();
+        Self { groups, details }
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<(GroupResultRef<'_>, &[ScoreDetails])> {
+        Some((self.groups.get(index)?, self.details.get(index)?.as_slice()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (GroupResultRef<'_>, &[ScoreDetails])> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+}
+
+/// How [`HybridQuery`] combines a dense ranked list and a sparse ranked
+/// list into one fused ranking.
+#[derive(Debug, Clone, Copy)]
+pub enum FusionMethod {
+    /// Reciprocal Rank Fusion: each doc's 1-based rank `r` in a list
+    /// contributes `1 / (k + r)` to its fused score, summed across lists.
+    /// Rank-based, so it is insensitive to the two lists' score scales.
+    Rrf {
+        /// Smoothing constant; 60 is the de-facto default in the literature.
+        k: f32,
+    },
+    /// Convex combination `alpha * norm(dense) + (1 - alpha) * norm(sparse)`
+    /// of each list's scores after min-max normalization to `[0, 1]`. A doc
+    /// missing from a list contributes 0 for that list's term.
+    Linear {
+        /// Weight given to the dense list; `1.0 - alpha` goes to sparse.
+        alpha: f32,
+    },
+}
+
+impl Default for FusionMethod {
+    fn default() -> Self {
+        FusionMethod::Rrf { k: 60.0 }
+    }
+}
+
+/// A hybrid dense-vector + sparse-vector + keyword search, fused into a
+/// single ranked result list.
+///
+/// Runs a dense [`VectorQuery`], a sparse [`VectorQuery`], and/or a
+/// BM25-ranked keyword search against their respective fields, widens each
+/// to a generous candidate pool, and fuses the ranked lists client-side
+/// according to the chosen [`FusionMethod`] before truncating to `topk`.
+/// The keyword leg is served from the client-side inverted index
+/// registered via
+/// [`Collection::create_text_index`](crate::collection::Collection::create_text_index);
+/// there is no native full-text index type in this build.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::query::{FusionMethod, HybridQuery};
+///
+/// let query = HybridQuery::new()
+///     .dense("embedding", &[0.1, 0.2, 0.3, 0.4])
+///     .sparse("keywords", &[3, 9], &[1.0, 0.5])
+///     .unwrap()
+///     .fusion(FusionMethod::Rrf { k: 60.0 })
+///     .topk(10);
+///
+/// // Blend vector similarity with keyword relevance instead:
+/// let blended = HybridQuery::new()
+///     .dense("embedding", &[0.1, 0.2, 0.3, 0.4])
+///     .keyword("body", "reciprocal rank fusion")
+///     .semantic_ratio(0.7)
+///     .topk(10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HybridQuery {
+    pub(crate) dense_field: Option<String>,
+    pub(crate) dense_vector: Option<Vec<f32>>,
+    pub(crate) sparse_field: Option<String>,
+    pub(crate) sparse_indices: Option<Vec<u32>>,
+    pub(crate) sparse_values: Option<Vec<f32>>,
+    pub(crate) keyword_field: Option<String>,
+    pub(crate) keyword_text: Option<String>,
+    pub(crate) extra_dense: Vec<(String, Vec<f32>)>,
+    pub(crate) fusion: FusionMethod,
+    pub(crate) topk: usize,
+    pub(crate) explain: bool,
+    /// Weight applied to the dense leg's contribution under
+    /// [`FusionMethod::Rrf`]; see [`Self::dense_weight`].
+    pub(crate) dense_weight: f32,
+    /// Weight applied to the sparse leg's contribution under
+    /// [`FusionMethod::Rrf`]; see [`Self::sparse_weight`].
+    pub(crate) sparse_weight: f32,
+}
+
+impl HybridQuery {
+    /// Create an empty hybrid query with a default `topk` of 10 and RRF
+    /// fusion (`k = 60`, dense and sparse weight `1.0` each).
+    pub fn new() -> Self {
+        Self {
+            topk: 10,
+            fusion: FusionMethod::default(),
+            dense_weight: 1.0,
+            sparse_weight: 1.0,
+            ..Default::default()
+        }
+    }
+
+    /// Set the dense vector search leg.
+    pub fn dense(mut self, field: &str, vector: &[f32]) -> Self {
+        self.dense_field = Some(field.to_string());
+        self.dense_vector = Some(vector.to_vec());
+        self
+    }
+
+    /// Set the sparse vector search leg.
+    pub fn sparse(mut self, field: &str, indices: &[u32], values: &[f32]) -> Result<Self> {
+        if indices.len() != values.len() {
+            return Err(crate::error::Error::InvalidArgument(
+                "indices and values must have same length".into(),
+            ));
+        }
+        self.sparse_field = Some(field.to_string());
+        self.sparse_indices = Some(indices.to_vec());
+        self.sparse_values = Some(values.to_vec());
+        Ok(self)
+    }
+
+    /// Add another dense-vector leg beyond the primary [`Self::dense`]
+    /// field, so more than one vector field (e.g. a title embedding and a
+    /// body embedding) can be fused into the same ranking rather than just
+    /// a single dense+sparse pair. Each call appends one more leg, fused by
+    /// rank with weight 1.0; under [`FusionMethod::Linear`] these legs still
+    /// fuse by RRF rank (k = 60) rather than taking a share of `alpha`.
+    pub fn extra_dense(mut self, field: &str, vector: &[f32]) -> Self {
+        self.extra_dense.push((field.to_string(), vector.to_vec()));
+        self
+    }
+
+    /// Set the keyword/full-text search leg: `text` is BM25-ranked against
+    /// the client-side inverted index registered for `field` via
+    /// [`Collection::create_text_index`](crate::collection::Collection::create_text_index).
+    pub fn keyword(mut self, field: &str, text: &str) -> Self {
+        self.keyword_field = Some(field.to_string());
+        self.keyword_text = Some(text.to_string());
+        self
+    }
+
+    /// Set the fusion method (default: [`FusionMethod::Rrf`] with `k = 60`).
+    pub fn fusion(mut self, fusion: FusionMethod) -> Self {
+        self.fusion = fusion;
+        self
+    }
+
+    /// Convenience for [`Self::fusion`] with [`FusionMethod::Linear`]:
+    /// `ratio` is the weight given to the dense leg, with `1.0 - ratio`
+    /// going to whichever of the sparse or keyword legs is set.
+    pub fn semantic_ratio(self, ratio: f32) -> Self {
+        self.fusion(FusionMethod::Linear { alpha: ratio })
+    }
+
+    /// Convenience for [`Self::fusion`] with [`FusionMethod::Rrf`] and the
+    /// given smoothing constant (default 60). Replaces any
+    /// [`FusionMethod::Linear`] fusion set earlier, e.g. via
+    /// [`Self::semantic_ratio`].
+    pub fn k(mut self, k: u32) -> Self {
+        self.fusion = FusionMethod::Rrf { k: k as f32 };
+        self
+    }
+
+    /// Weight applied to the dense leg's contribution under
+    /// [`FusionMethod::Rrf`] (default 1.0): each dense hit's rank-based
+    /// contribution `1 / (k + rank)` is multiplied by this before summing.
+    /// Has no effect under [`FusionMethod::Linear`], which weights legs via
+    /// [`Self::semantic_ratio`] instead.
+    pub fn dense_weight(mut self, weight: f32) -> Self {
+        self.dense_weight = weight;
+        self
+    }
+
+    /// Weight applied to the sparse leg's contribution under
+    /// [`FusionMethod::Rrf`] (default 1.0); see [`Self::dense_weight`].
+    pub fn sparse_weight(mut self, weight: f32) -> Self {
+        self.sparse_weight = weight;
+        self
+    }
+
+    /// Set the number of fused results to return (default: 10).
+    pub fn topk(mut self, topk: usize) -> Self {
+        self.topk = topk;
+        self
+    }
+
+    /// Opt into per-leg [`ScoreComponent`]s on each
+    /// [`HybridHitRef::score_details`], recording each hit's dense and/or
+    /// sparse contribution and fusion weight.
+    pub fn explain(mut self, enable: bool) -> Self {
+        self.explain = enable;
+        self
+    }
+
+    /// Size of the per-leg candidate pool fetched before fusion: large
+    /// enough that fusion sees more than just `topk` candidates from each
+    /// side, since a doc ranked outside one list's top-k can still fuse
+    /// into the final top-k on the strength of the other list.
+    pub(crate) fn candidate_k(&self) -> usize {
+        self.topk.saturating_mul(4).max(50)
+    }
+}
+
+/// One `(field, query_vector, weight)` leg of a [`MultiVectorQuery`].
+pub(crate) struct MultiVectorLeg {
+    pub(crate) field: String,
+    pub(crate) vector: Vec<f32>,
+    pub(crate) weight: f32,
+}
+
+/// A search across several dense-vector fields (e.g. a title embedding and
+/// a body embedding for the same document) fused into a single ranked
+/// result, run via
+/// [`Collection::multi_vector_query`](crate::collection::Collection::multi_vector_query).
+///
+/// Each field added with [`Self::field`] is run as its own [`VectorQuery`]
+/// (inheriting this query's `.filter()`/`.output_fields()`), and the
+/// resulting [`DocList`]s are combined with [`FusedResults::fuse_weighted`]:
+/// a doc's 1-based rank `r` in a field's list contributes
+/// `weight / (60 + r)` to its fused score, summed across fields.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::query::MultiVectorQuery;
+///
+/// let query = MultiVectorQuery::new()
+///     .field("title_embedding", &[0.1, 0.2, 0.3, 0.4], 2.0)
+///     .field("body_embedding", &[0.4, 0.3, 0.2, 0.1], 1.0)
+///     .topk(10);
+/// ```
+#[derive(Default)]
+pub struct MultiVectorQuery {
+    pub(crate) legs: Vec<MultiVectorLeg>,
+    pub(crate) filter: Option<String>,
+    pub(crate) output_fields: Option<Vec<String>>,
+    pub(crate) topk: usize,
+}
+
+impl MultiVectorQuery {
+    /// Create an empty multi-field query with a default `topk` of 10.
+    pub fn new() -> Self {
+        Self {
+            topk: 10,
+            ..Default::default()
+        }
+    }
+
+    /// Add a `field`/`query_vector` leg with a fusion `weight` (the same
+    /// `weight` meaning as [`HybridQuery::dense_weight`] under RRF).
+    pub fn field(mut self, field: &str, query_vector: &[f32], weight: f32) -> Self {
+        self.legs.push(MultiVectorLeg {
+            field: field.to_string(),
+            vector: query_vector.to_vec(),
+            weight,
+        });
+        self
+    }
+
+    /// Set a filter expression applied to every field's leg, narrowing
+    /// results the same way as [`VectorQuery::filter`].
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Set which fields to include in results, the same way as
+    /// [`VectorQuery::output_fields`].
+    pub fn output_fields(mut self, fields: &[&str]) -> Self {
+        self.output_fields = Some(fields.iter().map(|f| f.to_string()).collect());
+        self
+    }
+
+    /// Set the number of fused results to return (default: 10).
+    pub fn topk(mut self, topk: usize) -> Self {
+        self.topk = topk;
+        self
+    }
+
+    /// Size of the per-field candidate pool fetched before fusion, mirroring
+    /// [`HybridQuery::candidate_k`].
+    pub(crate) fn candidate_k(&self) -> usize {
+        self.topk.saturating_mul(4).max(50)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HybridSource {
+    Dense,
+    Sparse,
+    Keyword,
+    /// An [`HybridQuery::extra_dense`] leg, indexing into
+    /// [`HybridResults::extra`].
+    Extra(usize),
+}
+
+impl HybridSource {
+    fn label(self) -> &'static str {
+        match self {
+            HybridSource::Dense => "dense",
+            HybridSource::Sparse => "sparse",
+            HybridSource::Keyword => "keyword",
+            HybridSource::Extra(_) => "extra",
+        }
+    }
+}
+
+struct HybridHit {
+    source: HybridSource,
+    index: usize,
+    pk: String,
+    fused_score: f32,
+    normalized_score: f32,
+    components: Vec<ScoreComponent>,
+}
+
+/// The fused result of a [`HybridQuery`].
+///
+/// Keeps all underlying candidates alive (as [`DocList`]s for the dense,
+/// sparse, and [`HybridQuery::extra_dense`] legs, as a [`DocMap`] fetched by
+/// primary key for the keyword leg) and stores a fused, sorted order over
+/// them so [`HybridResults::get`] can hand back a reference into whichever
+/// one actually holds the doc.
+pub struct HybridResults {
+    dense: Option<DocList>,
+    sparse: Option<DocList>,
+    keyword: Option<DocMap>,
+    extra: Vec<DocList>,
+    order: Vec<HybridHit>,
+}
+
+impl HybridResults {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn fuse(
+        dense: Option<DocList>,
+        sparse: Option<DocList>,
+        keyword_ranked: Option<Vec<(String, f32)>>,
+        keyword_docs: Option<DocMap>,
+        extra: Vec<(String, DocList)>,
+        fusion: FusionMethod,
+        topk: usize,
+        explain: bool,
+        dense_weight: f32,
+        sparse_weight: f32,
+    ) -> Self {
+        let (extra_labels, extra): (Vec<String>, Vec<DocList>) = extra.into_iter().unzip();
+        let mut scores: HashMap<String, f32> = HashMap::new();
+        let mut first_seen: HashMap<String, (HybridSource, usize)> = HashMap::new();
+        let mut components: HashMap<String, Vec<ScoreComponent>> = HashMap::new();
+
+        match fusion {
+            FusionMethod::Rrf { k } => {
+                for (source, list, weight) in [
+                    (HybridSource::Dense, &dense, dense_weight),
+                    (HybridSource::Sparse, &sparse, sparse_weight),
+                ] {
+                    let Some(list) = list else { continue };
+                    for (rank, doc) in list.iter().enumerate() {
+                        let pk = doc.pk().to_string();
+                        let contribution = weight / (k + rank as f32 + 1.0);
+                        *scores.entry(pk.clone()).or_insert(0.0) += contribution;
+                        first_seen.entry(pk.clone()).or_insert((source, rank));
+                        if explain {
+                            components.entry(pk).or_default().push(ScoreComponent {
+                                source: source.label().to_string(),
+                                score: contribution,
+                                weight,
+                            });
+                        }
+                    }
+                }
+                for (idx, list) in extra.iter().enumerate() {
+                    let source = HybridSource::Extra(idx);
+                    for (rank, doc) in list.iter().enumerate() {
+                        let pk = doc.pk().to_string();
+                        let contribution = 1.0 / (k + rank as f32 + 1.0);
+                        *scores.entry(pk.clone()).or_insert(0.0) += contribution;
+                        first_seen.entry(pk.clone()).or_insert((source, rank));
+                        if explain {
+                            components.entry(pk).or_default().push(ScoreComponent {
+                                source: extra_labels[idx].clone(),
+                                score: contribution,
+                                weight: 1.0,
+                            });
+                        }
+                    }
+                }
+                if let Some(ranked) = &keyword_ranked {
+                    for (rank, (pk, _)) in ranked.iter().enumerate() {
+                        let contribution = 1.0 / (k + rank as f32 + 1.0);
+                        *scores.entry(pk.clone()).or_insert(0.0) += contribution;
+                        first_seen
+                            .entry(pk.clone())
+                            .or_insert((HybridSource::Keyword, rank));
+                        if explain {
+                            components
+                                .entry(pk.clone())
+                                .or_default()
+                                .push(ScoreComponent {
+                                    source: HybridSource::Keyword.label().to_string(),
+                                    score: contribution,
+                                    weight: 1.0,
+                                });
+                        }
+                    }
+                }
+            }
+            FusionMethod::Linear { alpha } => {
+                for (source, list, weight) in [
+                    (HybridSource::Dense, &dense, alpha),
+                    (HybridSource::Sparse, &sparse, 1.0 - alpha),
+                ] {
+                    let Some(list) = list else { continue };
+                    for (pk, norm_score) in normalize_scores(list) {
+                        *scores.entry(pk.clone()).or_insert(0.0) += weight * norm_score;
+                        if explain {
+                            components.entry(pk).or_default().push(ScoreComponent {
+                                source: source.label().to_string(),
+                                score: norm_score,
+                                weight,
+                            });
+                        }
+                    }
+                    for (rank, doc) in list.iter().enumerate() {
+                        first_seen
+                            .entry(doc.pk().to_string())
+                            .or_insert((source, rank));
+                    }
+                }
+                // `extra_dense` legs have no natural place in a two-way
+                // dense/sparse `alpha` split, so they always fuse by RRF
+                // rank (k = 60) regardless of the chosen `FusionMethod`.
+                for (idx, list) in extra.iter().enumerate() {
+                    let source = HybridSource::Extra(idx);
+                    for (rank, doc) in list.iter().enumerate() {
+                        let pk = doc.pk().to_string();
+                        let contribution = 1.0 / (60.0 + rank as f32 + 1.0);
+                        *scores.entry(pk.clone()).or_insert(0.0) += contribution;
+                        first_seen.entry(pk.clone()).or_insert((source, rank));
+                        if explain {
+                            components.entry(pk).or_default().push(ScoreComponent {
+                                source: extra_labels[idx].clone(),
+                                score: contribution,
+                                weight: 1.0,
+                            });
+                        }
+                    }
+                }
+                if let Some(ranked) = &keyword_ranked {
+                    let weight = 1.0 - alpha;
+                    for (pk, norm_score) in normalize_keyword_scores(ranked) {
+                        *scores.entry(pk.clone()).or_insert(0.0) += weight * norm_score;
+                        if explain {
+                            components.entry(pk).or_default().push(ScoreComponent {
+                                source: HybridSource::Keyword.label().to_string(),
+                                score: norm_score,
+                                weight,
+                            });
+                        }
+                    }
+                    for (rank, (pk, _)) in ranked.iter().enumerate() {
+                        first_seen
+                            .entry(pk.clone())
+                            .or_insert((HybridSource::Keyword, rank));
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<HybridHit> = scores
+            .into_iter()
+            .filter_map(|(pk, fused_score)| {
+                first_seen.get(&pk).map(|&(source, index)| HybridHit {
+                    source,
+                    index,
+                    pk: pk.clone(),
+                    fused_score,
+                    normalized_score: 0.0,
+                    components: components.get(&pk).cloned().unwrap_or_default(),
+                })
+            })
+            .collect();
+        order.sort_by(|a, b| {
+            b.fused_score
+                .partial_cmp(&a.fused_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.pk.cmp(&b.pk))
+        });
+        order.truncate(topk);
+
+        let min = order
+            .iter()
+            .map(|h| h.fused_score)
+            .fold(f32::INFINITY, f32::min);
+        let max = order
+            .iter()
+            .map(|h| h.fused_score)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let range = (max - min).max(f32::EPSILON);
+        for hit in &mut order {
+            hit.normalized_score = (hit.fused_score - min) / range;
+        }
+
+        Self {
+            dense,
+            sparse,
+            keyword: keyword_docs,
+            extra,
+            order,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<HybridHitRef<'_>> {
+        let hit = self.order.get(index)?;
+        let doc = match hit.source {
+            HybridSource::Dense => self.dense.as_ref()?.get(hit.index)?,
+            HybridSource::Sparse => self.sparse.as_ref()?.get(hit.index)?,
+            HybridSource::Keyword => self.keyword.as_ref()?.get(&hit.pk)?,
+            HybridSource::Extra(idx) => self.extra.get(idx)?.get(hit.index)?,
+        };
+        Some(HybridHitRef {
+            doc,
+            fused_score: hit.fused_score,
+            normalized_score: hit.normalized_score,
+            rank: index,
+            components: &hit.components,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = HybridHitRef<'_>> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+}
+
+/// A single fused hit: the underlying document plus its fused score.
+pub struct HybridHitRef<'a> {
+    doc: crate::doc::DocRef<'a>,
+    fused_score: f32,
+    normalized_score: f32,
+    rank: usize,
+    components: &'a [ScoreComponent],
+}
+
+impl<'a> HybridHitRef<'a> {
+    /// The fused score that determined this hit's rank, not the raw score
+    /// from either contributing leg.
+    pub fn fused_score(&self) -> f32 {
+        self.fused_score
+    }
+
+    /// The underlying document, with access to its original per-leg score
+    /// via [`crate::doc::DocRef::score`].
+    pub fn doc(&self) -> &crate::doc::DocRef<'a> {
+        &self.doc
+    }
+
+    /// This hit's fused-score breakdown. `components` is only populated
+    /// when the originating [`HybridQuery`] was built with `.explain(true)`.
+    pub fn score_details(&self) -> ScoreDetails {
+        ScoreDetails {
+            metric: MetricType::Undefined,
+            raw_score: self.fused_score,
+            normalized_score: self.normalized_score,
+            rank: self.rank,
+            filter_matched: None,
+            components: self.components.to_vec(),
+        }
+    }
+}
+
+fn normalize_scores(list: &DocList) -> Vec<(String, f32)> {
+    let scores: Vec<(String, f32)> = list
+        .iter()
+        .map(|d| (d.pk().to_string(), d.score()))
+        .collect();
+    let min = scores.iter().map(|(_, s)| *s).fold(f32::INFINITY, f32::min);
+    let max = scores
+        .iter()
+        .map(|(_, s)| *s)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+    scores
+        .into_iter()
+        .map(|(pk, s)| (pk, (s - min) / range))
+        .collect()
+}
+
+fn normalize_keyword_scores(ranked: &[(String, f32)]) -> Vec<(String, f32)> {
+    let min = ranked.iter().map(|(_, s)| *s).fold(f32::INFINITY, f32::min);
+    let max = ranked
+        .iter()
+        .map(|(_, s)| *s)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+    ranked
+        .iter()
+        .map(|(pk, s)| (pk.clone(), (s - min) / range))
+        .collect()
+}
+
+/// Reciprocal Rank Fusion over independently-run [`DocList`]s.
+///
+/// Unlike [`HybridResults::fuse`], which fuses legs a [`Collection`](crate::collection::Collection)
+/// ran itself, this works purely over [`DocList`]s the caller already has -
+/// e.g. a dense [`VectorQuery`] run against one field and a sparse
+/// [`VectorQuery`] run against another - so two independent queries can be
+/// merged into one ranking without the collection needing a native hybrid
+/// mode.
+///
+/// Each list is assumed already sorted by descending [`DocRef::score`] (as
+/// every query result is); a doc at 1-based rank `r` in a list contributes
+/// `1.0 / (k + r)` (default `k = 60.0`), summed by [`DocRef::pk`] across all
+/// lists - a doc absent from a list contributes nothing for it. The summed
+/// scores are sorted descending, ties broken by `pk`, and truncated to
+/// `topk`.
+pub fn fuse_results(lists: &[&DocList], k: Option<f64>, topk: usize) -> Vec<(String, f32)> {
+    let k = k.unwrap_or(60.0);
+    let mut scores: HashMap<String, f64> = HashMap::new();
+    for list in lists {
+        for (rank, doc) in list.iter().enumerate() {
+            let contribution = 1.0 / (k + (rank + 1) as f64);
+            *scores.entry(doc.pk().to_string()).or_insert(0.0) += contribution;
+        }
+    }
+
+    let mut ranked: Vec<(String, f32)> = scores
+        .into_iter()
+        .map(|(pk, score)| (pk, score as f32))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(topk);
+    ranked
+}
+
+/// Like [`fuse_results`], but each list's contribution is multiplied by a
+/// per-list weight before summing, for [`MultiVectorQuery`]'s per-field
+/// weighting.
+pub fn fuse_results_weighted(
+    lists: &[(&DocList, f32)],
+    k: Option<f64>,
+    topk: usize,
+) -> Vec<(String, f32)> {
+    let k = k.unwrap_or(60.0);
+    let mut scores: HashMap<String, f64> = HashMap::new();
+    for (list, weight) in lists {
+        for (rank, doc) in list.iter().enumerate() {
+            let contribution = *weight as f64 / (k + (rank + 1) as f64);
+            *scores.entry(doc.pk().to_string()).or_insert(0.0) += contribution;
+        }
+    }
+
+    let mut ranked: Vec<(String, f32)> = scores
+        .into_iter()
+        .map(|(pk, score)| (pk, score as f32))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(topk);
+    ranked
+}
+
+struct FusedHit {
+    list_idx: usize,
+    item_idx: usize,
+    fused_score: f32,
+    normalized_score: f32,
+    components: Vec<ScoreComponent>,
+}
+
+/// Owning counterpart to [`fuse_results`]: keeps the input [`DocList`]s
+/// alive so [`Self::get`]/[`Self::iter`] can hand back a [`FusedHitRef`] into
+/// whichever one actually holds each fused doc, alongside its fused score
+/// and per-list [`ScoreComponent`] breakdown.
+pub struct FusedResults {
+    lists: Vec<DocList>,
+    order: Vec<FusedHit>,
+}
+
+impl FusedResults {
+    /// Run [`fuse_results`] over `lists` and keep them alive for lookup.
+    pub fn fuse(lists: Vec<DocList>, k: Option<f64>, topk: usize) -> Self {
+        let refs: Vec<&DocList> = lists.iter().collect();
+        let ranked = fuse_results(&refs, k, topk);
+        let k = k.unwrap_or(60.0);
+
+        let mut first_seen: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut components: HashMap<String, Vec<ScoreComponent>> = HashMap::new();
+        for (list_idx, list) in lists.iter().enumerate() {
+            for (item_idx, doc) in list.iter().enumerate() {
+                let pk = doc.pk().to_string();
+                first_seen.entry(pk.clone()).or_insert((list_idx, item_idx));
+                let contribution = 1.0 / (k + (item_idx + 1) as f64);
+                components.entry(pk).or_default().push(ScoreComponent {
+                    source: format!("list{list_idx}"),
+                    score: contribution as f32,
+                    weight: 1.0,
+                });
+            }
+        }
+
+        let mut order: Vec<FusedHit> = ranked
+            .into_iter()
+            .filter_map(|(pk, fused_score)| {
+                first_seen.get(&pk).map(|&(list_idx, item_idx)| FusedHit {
+                    list_idx,
+                    item_idx,
+                    fused_score,
+                    normalized_score: 0.0,
+                    components: components.get(&pk).cloned().unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        let min = order
+            .iter()
+            .map(|h| h.fused_score)
+            .fold(f32::INFINITY, f32::min);
+        let max = order
+            .iter()
+            .map(|h| h.fused_score)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let range = (max - min).max(f32::EPSILON);
+        for hit in &mut order {
+            hit.normalized_score = (hit.fused_score - min) / range;
+        }
+
+        Self { lists, order }
+    }
+
+    /// Like [`Self::fuse`], but each list carries its own fusion weight -
+    /// used by [`crate::collection::Collection::multi_vector_query`] to
+    /// blend several [`MultiVectorQuery`] fields with different importance.
+    pub fn fuse_weighted(lists: Vec<(DocList, f32)>, k: Option<f64>, topk: usize) -> Self {
+        let refs: Vec<(&DocList, f32)> = lists.iter().map(|(l, w)| (l, *w)).collect();
+        let ranked = fuse_results_weighted(&refs, k, topk);
+        let k = k.unwrap_or(60.0);
+
+        let mut first_seen: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut components: HashMap<String, Vec<ScoreComponent>> = HashMap::new();
+        for (list_idx, (list, weight)) in lists.iter().enumerate() {
+            for (item_idx, doc) in list.iter().enumerate() {
+                let pk = doc.pk().to_string();
+                first_seen.entry(pk.clone()).or_insert((list_idx, item_idx));
+                let contribution = 1.0 / (k + (item_idx + 1) as f64);
+                components.entry(pk).or_default().push(ScoreComponent {
+                    source: format!("list{list_idx}"),
+                    score: contribution as f32,
+                    weight: *weight,
+                });
+            }
+        }
+
+        let mut order: Vec<FusedHit> = ranked
+            .into_iter()
+            .filter_map(|(pk, fused_score)| {
+                first_seen.get(&pk).map(|&(list_idx, item_idx)| FusedHit {
+                    list_idx,
+                    item_idx,
+                    fused_score,
+                    normalized_score: 0.0,
+                    components: components.get(&pk).cloned().unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        let min = order
+            .iter()
+            .map(|h| h.fused_score)
+            .fold(f32::INFINITY, f32::min);
+        let max = order
+            .iter()
+            .map(|h| h.fused_score)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let range = (max - min).max(f32::EPSILON);
+        for hit in &mut order {
+            hit.normalized_score = (hit.fused_score - min) / range;
+        }
+
+        let lists = lists.into_iter().map(|(l, _)| l).collect();
+        Self { lists, order }
+    }
+
+    /// Number of fused results.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether there are no fused results.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// The fused hit at fused rank `index`, if any.
+    pub fn get(&self, index: usize) -> Option<FusedHitRef<'_>> {
+        let hit = self.order.get(index)?;
+        let doc = self.lists[hit.list_idx].get(hit.item_idx)?;
+        Some(FusedHitRef {
+            doc,
+            fused_score: hit.fused_score,
+            normalized_score: hit.normalized_score,
+            rank: index,
+            components: &hit.components,
+        })
+    }
+
+    /// Iterate over fused hits in descending fused-score order.
+    pub fn iter(&self) -> impl Iterator<Item = FusedHitRef<'_>> + '_ {
+        (0..self.order.len()).filter_map(move |i| self.get(i))
+    }
+}
+
+/// A single hit from [`FusedResults`]: the underlying document plus its
+/// fused score and per-list breakdown.
+pub struct FusedHitRef<'a> {
+    doc: DocRef<'a>,
+    fused_score: f32,
+    normalized_score: f32,
+    rank: usize,
+    components: &'a [ScoreComponent],
+}
+
+impl<'a> FusedHitRef<'a> {
+    /// The fused score that determined this hit's rank.
+    pub fn fused_score(&self) -> f32 {
+        self.fused_score
+    }
+
+    /// The underlying document, with access to its original per-list score
+    /// via [`crate::doc::DocRef::score`].
+    pub fn doc(&self) -> &DocRef<'a> {
+        &self.doc
+    }
+
+    /// This hit's fused-score breakdown: one [`ScoreComponent`] per source
+    /// [`DocList`] it appeared in (`source` is `"list{n}"` for the `n`th
+    /// list passed to [`FusedResults::fuse`]).
+    pub fn score_details(&self) -> ScoreDetails {
+        ScoreDetails {
+            metric: MetricType::Undefined,
+            raw_score: self.fused_score,
+            normalized_score: self.normalized_score,
+            rank: self.rank,
+            filter_matched: None,
+            components: self.components.to_vec(),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! An in-process LRU cache of [`VectorQuery`](crate::query::VectorQuery) /
+//! [`GroupByVectorQuery`](crate::query::GroupByVectorQuery) results, used by
+//! [`crate::sync::SharedCollection::with_cache`].
+//!
+//! Keyed on [`QueryCacheKey`](crate::query::QueryCacheKey), the normalized
+//! shape of a query (field, topk, filter, vector bytes, metric, required
+//! snapshot). Entries are shared via `Arc` so a cache hit is a cheap clone
+//! rather than a re-fetch, and any write on the owning collection clears the
+//! whole cache rather than trying to reason about which entries it could
+//! have invalidated.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::doc::DocList;
+use crate::query::{GroupResults, QueryCacheKey};
+
+struct LruMap<V> {
+    capacity: usize,
+    entries: HashMap<QueryCacheKey, V>,
+    /// Most-recently-used keys at the back; used to pick an eviction
+    /// candidate. Kept separate from `entries` rather than an ordered map
+    /// since this crate has no dependency on one.
+    recency: Vec<QueryCacheKey>,
+}
+
+impl<V> LruMap<V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: Vec::new(),
+        }
+    }
+
+    fn touch(&mut self, key: &QueryCacheKey) {
+        if let Some(pos) = self.recency.iter().position(|k| k == key) {
+            let key = self.recency.remove(pos);
+            self.recency.push(key);
+        }
+    }
+
+    fn get(&mut self, key: &QueryCacheKey) -> Option<&V> {
+        if self.entries.contains_key(key) {
+            self.touch(key);
+            self.entries.get(key)
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, key: QueryCacheKey, value: V) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            if !self.recency.is_empty() {
+                let evict = self.recency.remove(0);
+                self.entries.remove(&evict);
+            }
+        }
+        self.touch(&key);
+        if !self.recency.contains(&key) {
+            self.recency.push(key.clone());
+        }
+        self.entries.insert(key, value);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+}
+
+/// Caches [`VectorQuery`](crate::query::VectorQuery) results keyed by
+/// [`QueryCacheKey`], and [`GroupByVectorQuery`](crate::query::GroupByVectorQuery)
+/// results keyed the same way in a separate map, so the two query kinds
+/// never collide even if their cache keys happened to match.
+pub(crate) struct QueryCache {
+    queries: Mutex<LruMap<Arc<DocList>>>,
+    group_queries: Mutex<LruMap<Arc<GroupResults>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl QueryCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            queries: Mutex::new(LruMap::new(capacity.max(1))),
+            group_queries: Mutex::new(LruMap::new(capacity.max(1))),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn get_query(&self, key: &QueryCacheKey) -> Option<Arc<DocList>> {
+        let mut queries = self.queries.lock().expect("query cache lock poisoned");
+        match queries.get(key) {
+            Some(hit) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(Arc::clone(hit))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn insert_query(&self, key: QueryCacheKey, value: Arc<DocList>) {
+        self.queries
+            .lock()
+            .expect("query cache lock poisoned")
+            .insert(key, value);
+    }
+
+    pub(crate) fn get_group_query(&self, key: &QueryCacheKey) -> Option<Arc<GroupResults>> {
+        let mut group_queries = self
+            .group_queries
+            .lock()
+            .expect("query cache lock poisoned");
+        match group_queries.get(key) {
+            Some(hit) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(Arc::clone(hit))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn insert_group_query(&self, key: QueryCacheKey, value: Arc<GroupResults>) {
+        self.group_queries
+            .lock()
+            .expect("query cache lock poisoned")
+            .insert(key, value);
+    }
+
+    /// Drop every cached entry. Called on any write to the owning
+    /// collection, since this cache has no way to tell which entries a
+    /// given write could have affected.
+    pub(crate) fn invalidate(&self) {
+        self.queries
+            .lock()
+            .expect("query cache lock poisoned")
+            .clear();
+        self.group_queries
+            .lock()
+            .expect("query cache lock poisoned")
+            .clear();
+    }
+
+    pub(crate) fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,740 @@
+use std::ffi::CString;
+
+use crate::embed::EmbedderMapping;
+use crate::error::{check_status, Error, Result};
+use crate::ffi;
+use crate::types::DataType;
+
+pub struct FieldSchema {
+    pub(crate) ptr: *mut ffi::zvec_field_schema_t,
+    dictionary_encoded: bool,
+}
+
+impl FieldSchema {
+    pub fn new(name: &str, data_type: DataType) -> Self {
+        let name_c = CString::new(name).unwrap();
+        let ptr = unsafe { ffi::zvec_field_schema_new(name_c.as_ptr(), data_type.into()) };
+        Self {
+            ptr,
+            dictionary_encoded: false,
+        }
+    }
+
+    pub fn new_vector(name: &str, data_type: DataType, dimension: u32) -> Self {
+        let name_c = CString::new(name).unwrap();
+        let ptr = unsafe {
+            ffi::zvec_field_schema_new_with_dimension(name_c.as_ptr(), data_type.into(), dimension)
+        };
+        Self {
+            ptr,
+            dictionary_encoded: false,
+        }
+    }
+
+    pub fn bool_(name: &str) -> Self {
+        Self::new(name, DataType::Bool)
+    }
+
+    pub fn int32(name: &str) -> Self {
+        Self::new(name, DataType::Int32)
+    }
+
+    pub fn int64(name: &str) -> Self {
+        Self::new(name, DataType::Int64)
+    }
+
+    pub fn float(name: &str) -> Self {
+        Self::new(name, DataType::Float)
+    }
+
+    pub fn double(name: &str) -> Self {
+        Self::new(name, DataType::Double)
+    }
+
+    pub fn string(name: &str) -> Self {
+        Self::new(name, DataType::String)
+    }
+
+    pub fn set_nullable(&mut self, nullable: bool) {
+        unsafe { ffi::zvec_field_schema_set_nullable(self.ptr, nullable) };
+    }
+
+    /// Record, as schema metadata only, that this field's string values are
+    /// intended to be front-coded (see [`crate::dictionary::FrontCodedDictionary`]
+    /// for the encoding a caller can apply themselves).
+    ///
+    /// This build has no client-side storage path for field values - they go
+    /// straight through FFI (e.g. [`crate::doc::Doc::set_string`]) to the
+    /// native index, which this flag has no way to configure. Setting it
+    /// changes nothing about how values are stored or read back. The flag
+    /// lives on this `FieldSchema` value only: [`CollectionSchema::add_field`]
+    /// does not keep it, so fields read back via
+    /// [`CollectionSchema::field_at`]/[`CollectionSchema::fields`] (and hence
+    /// a [`CollectionSchema`]'s cbor/serde forms) report `false`, and
+    /// [`CollectionSchema::diff`] does not compare it.
+    ///
+    /// **Status: not wired up, and not scheduled.** This isn't a stub
+    /// awaiting a follow-up patch - actually compressing string storage
+    /// would require this crate to own a client-side field storage layer it
+    /// does not have today, which is a different-shaped project than this
+    /// flag. Treat it as a non-goal for this build, not a delivered memory
+    /// optimization.
+    pub fn dictionary_encoded(mut self, enabled: bool) -> Self {
+        self.dictionary_encoded = enabled;
+        self
+    }
+
+    /// Whether [`Self::dictionary_encoded`] was set on this field. Schema
+    /// metadata only - see its doc comment for why this doesn't change how
+    /// the field is actually stored.
+    pub fn is_dictionary_encoded(&self) -> bool {
+        self.dictionary_encoded
+    }
+
+    pub fn name(&self) -> &str {
+        unsafe {
+            let ptr = ffi::zvec_field_schema_name(self.ptr);
+            if ptr.is_null() {
+                ""
+            } else {
+                std::ffi::CStr::from_ptr(ptr).to_str().unwrap_or("")
+            }
+        }
+    }
+
+    pub fn data_type(&self) -> DataType {
+        unsafe { ffi::zvec_field_schema_data_type(self.ptr).into() }
+    }
+
+    pub fn nullable(&self) -> bool {
+        unsafe { ffi::zvec_field_schema_nullable(self.ptr) }
+    }
+
+    pub fn dimension(&self) -> u32 {
+        unsafe { ffi::zvec_field_schema_dimension(self.ptr) }
+    }
+}
+
+impl Drop for FieldSchema {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_field_schema_free(self.ptr) };
+        }
+    }
+}
+
+/// Compares by declared shape (name, type, dimension, nullability, and
+/// dictionary encoding) read back through the accessors, not by FFI pointer
+/// identity.
+impl PartialEq for FieldSchema {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+            && self.data_type() == other.data_type()
+            && self.dimension() == other.dimension()
+            && self.nullable() == other.nullable()
+            && self.is_dictionary_encoded() == other.is_dictionary_encoded()
+    }
+}
+
+pub struct CollectionSchema {
+    pub(crate) ptr: *mut ffi::zvec_collection_schema_t,
+    embedder_mappings: Vec<EmbedderMapping>,
+}
+
+impl CollectionSchema {
+    pub fn new(name: &str) -> Self {
+        let name_c = CString::new(name).unwrap();
+        let ptr = unsafe { ffi::zvec_collection_schema_new(name_c.as_ptr()) };
+        Self {
+            ptr,
+            embedder_mappings: Vec::new(),
+        }
+    }
+
+    /// Start a [`CollectionSchemaBuilder`] for declaring a whole schema in
+    /// one chained expression instead of repeated `add_field` calls.
+    pub fn builder(name: &str) -> CollectionSchemaBuilder {
+        CollectionSchemaBuilder::new(name)
+    }
+
+    /// Register `source_field` (a `String`/`ArrayString` field) as the text
+    /// source to auto-embed into `target_field` (a `VectorFp32` field)
+    /// whenever `Collection::insert`/`upsert` sees a document with
+    /// `source_field` set but `target_field` absent.
+    ///
+    /// `target_field` must already have been added via [`Self::add_field`],
+    /// since its dimension is recorded here for the
+    /// [`IdentityEmbedder`](crate::embed::IdentityEmbedder) fallback used
+    /// until a real [`Embedder`](crate::embed::Embedder) is registered with
+    /// [`Collection::set_embedder`](crate::collection::Collection::set_embedder).
+    /// The mapping travels with the schema so [`Collection::create_and_open`]
+    /// and [`Collection::open_with_schema`](crate::collection::Collection::open_with_schema)
+    /// both know which fields to auto-embed.
+    pub fn register_embedder(&mut self, source_field: &str, target_field: &str) -> Result<()> {
+        let dimension = self
+            .fields()
+            .iter()
+            .find(|f| f.name() == target_field)
+            .map(|f| f.dimension())
+            .ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "target field '{target_field}' must be added to the schema before registering an embedder"
+                ))
+            })?;
+
+        self.embedder_mappings.push(EmbedderMapping {
+            source_field: source_field.to_string(),
+            target_field: target_field.to_string(),
+            target_dimension: dimension,
+        });
+        Ok(())
+    }
+
+    /// Source-field -> target-field embedder mappings registered with
+    /// [`Self::register_embedder`], in registration order.
+    pub fn embedder_mappings(&self) -> &[EmbedderMapping] {
+        &self.embedder_mappings
+    }
+
+    pub fn add_field(&mut self, field: FieldSchema) -> Result<()> {
+        let status = unsafe { ffi::zvec_collection_schema_add_field(self.ptr, field.ptr) };
+        check_status(status)
+    }
+
+    pub fn name(&self) -> &str {
+        unsafe {
+            let ptr = ffi::zvec_collection_schema_name(self.ptr);
+            if ptr.is_null() {
+                ""
+            } else {
+                std::ffi::CStr::from_ptr(ptr).to_str().unwrap_or("")
+            }
+        }
+    }
+
+    /// Number of fields added to this schema.
+    pub fn field_count(&self) -> usize {
+        unsafe { ffi::zvec_collection_schema_field_count(self.ptr) }
+    }
+
+    /// The field at `index`, or `None` if out of range.
+    ///
+    /// The native field pointer at `index` belongs to this `CollectionSchema`
+    /// and is only valid for as long as it is, so this reads it back into a
+    /// genuinely independent [`FieldSchema`] rather than handing out a
+    /// wrapper around a borrowed pointer with no lifetime tying it to
+    /// `self` - that would let a caller keep the returned `FieldSchema`
+    /// alive past this schema's `Drop` and dereference freed memory
+    /// through it.
+    pub fn field_at(&self, index: usize) -> Option<FieldSchema> {
+        if index >= self.field_count() {
+            return None;
+        }
+        let ptr = unsafe { ffi::zvec_collection_schema_field_at(self.ptr, index) };
+        if ptr.is_null() {
+            return None;
+        }
+        let name = unsafe {
+            let name_ptr = ffi::zvec_field_schema_name(ptr);
+            if name_ptr.is_null() {
+                String::new()
+            } else {
+                std::ffi::CStr::from_ptr(name_ptr)
+                    .to_str()
+                    .unwrap_or("")
+                    .to_string()
+            }
+        };
+        let data_type: DataType = unsafe { ffi::zvec_field_schema_data_type(ptr).into() };
+        let dimension = unsafe { ffi::zvec_field_schema_dimension(ptr) };
+        let nullable = unsafe { ffi::zvec_field_schema_nullable(ptr) };
+
+        let mut copy = if dimension > 0 {
+            FieldSchema::new_vector(&name, data_type, dimension)
+        } else {
+            FieldSchema::new(&name, data_type)
+        };
+        copy.set_nullable(nullable);
+        Some(copy)
+    }
+
+    /// All fields added to this schema, in insertion order.
+    pub fn fields(&self) -> Vec<FieldSchema> {
+        (0..self.field_count())
+            .filter_map(|i| self.field_at(i))
+            .collect()
+    }
+}
+
+impl Drop for CollectionSchema {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::zvec_collection_schema_free(self.ptr) };
+        }
+    }
+}
+
+/// Compares by name, fields (in order, via [`FieldSchema`]'s own
+/// [`PartialEq`]), and registered embedder mappings - not by FFI pointer
+/// identity.
+impl PartialEq for CollectionSchema {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+            && self.fields() == other.fields()
+            && self.embedder_mappings() == other.embedder_mappings()
+    }
+}
+
+/// One field-level change reported by [`CollectionSchema::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldChange {
+    /// The field's [`DataType`] changed (e.g. `Int32` -> `Int64`).
+    DataType { from: DataType, to: DataType },
+    /// A vector field's dimension changed.
+    Dimension { from: u32, to: u32 },
+    /// The field went from required to nullable, or vice versa.
+    Nullable { from: bool, to: bool },
+}
+
+/// The result of [`CollectionSchema::diff`]: fields added and removed by
+/// name, plus per-field changes for fields present in both schemas.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SchemaDiff {
+    /// Fields present in the new schema but not the old one, in the new
+    /// schema's order.
+    pub added: Vec<String>,
+    /// Fields present in the old schema but not the new one, in the old
+    /// schema's order.
+    pub removed: Vec<String>,
+    /// Fields present in both schemas whose [`FieldSchema`] differs, with
+    /// every change detected for that field, in the old schema's field
+    /// order.
+    pub changed: Vec<(String, Vec<FieldChange>)>,
+}
+
+impl SchemaDiff {
+    /// Whether the two schemas compared were identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The result of [`CollectionSchema::compatible_with`]: how a proposed
+/// schema change relates to a currently-deployed schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compatibility {
+    /// The schemas are identical.
+    Identical,
+    /// Old readers/writers (the deployed schema) keep working against the
+    /// new schema: only nullable fields were added. Safe to roll out the
+    /// new schema without touching already-written data.
+    BackwardCompatible,
+    /// New readers/writers (the proposed schema) can still read data
+    /// written under the old schema: fields were only removed, never
+    /// changed or reinterpreted. Safe to roll back to the old schema.
+    ForwardCompatible,
+    /// Some field's type, dimension, or nullability changed, or a
+    /// non-nullable field was added - existing data may no longer parse
+    /// under the new schema. Requires a migration.
+    Breaking,
+}
+
+impl CollectionSchema {
+    /// Compare this schema (treated as the new one) against `other` (the
+    /// old one), reporting added/removed fields and per-field changes.
+    pub fn diff(&self, other: &CollectionSchema) -> SchemaDiff {
+        let new_fields = self.fields();
+        let old_fields = other.fields();
+
+        let added = new_fields
+            .iter()
+            .map(|f| f.name().to_string())
+            .filter(|name| !old_fields.iter().any(|f| f.name() == name))
+            .collect();
+
+        let removed = old_fields
+            .iter()
+            .map(|f| f.name().to_string())
+            .filter(|name| !new_fields.iter().any(|f| f.name() == name))
+            .collect();
+
+        let mut changed = Vec::new();
+        for old_field in &old_fields {
+            let Some(new_field) = new_fields.iter().find(|f| f.name() == old_field.name()) else {
+                continue;
+            };
+
+            let mut field_changes = Vec::new();
+            if old_field.data_type() != new_field.data_type() {
+                field_changes.push(FieldChange::DataType {
+                    from: old_field.data_type(),
+                    to: new_field.data_type(),
+                });
+            }
+            if old_field.dimension() != new_field.dimension() {
+                field_changes.push(FieldChange::Dimension {
+                    from: old_field.dimension(),
+                    to: new_field.dimension(),
+                });
+            }
+            if old_field.nullable() != new_field.nullable() {
+                field_changes.push(FieldChange::Nullable {
+                    from: old_field.nullable(),
+                    to: new_field.nullable(),
+                });
+            }
+            if !field_changes.is_empty() {
+                changed.push((old_field.name().to_string(), field_changes));
+            }
+        }
+
+        SchemaDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    /// Classify this schema (treated as the proposed new one) against
+    /// `deployed` (the currently-running schema).
+    ///
+    /// Any per-field [`FieldChange`], or adding a non-nullable field, is
+    /// [`Compatibility::Breaking`]. Otherwise, fields added (always
+    /// nullable, since a non-nullable addition is breaking) make it
+    /// [`Compatibility::BackwardCompatible`]; fields removed alone make it
+    /// [`Compatibility::ForwardCompatible`]; both added and removed with no
+    /// other changes is still [`Compatibility::Breaking`], since neither
+    /// side can read the other's data in full.
+    pub fn compatible_with(&self, deployed: &CollectionSchema) -> Compatibility {
+        let diff = self.diff(deployed);
+
+        if diff.is_empty() {
+            return Compatibility::Identical;
+        }
+        if !diff.changed.is_empty() {
+            return Compatibility::Breaking;
+        }
+
+        let added_non_nullable = diff.added.iter().any(|name| {
+            self.fields()
+                .iter()
+                .find(|f| f.name() == name)
+                .map(|f| !f.nullable())
+                .unwrap_or(false)
+        });
+        if added_non_nullable {
+            return Compatibility::Breaking;
+        }
+
+        match (diff.added.is_empty(), diff.removed.is_empty()) {
+            (false, true) => Compatibility::BackwardCompatible,
+            (true, false) => Compatibility::ForwardCompatible,
+            _ => Compatibility::Breaking,
+        }
+    }
+}
+
+/// Fluent builder for [`CollectionSchema`], started via
+/// [`CollectionSchema::builder`]. Each method appends one field and defers
+/// error checking to [`Self::build`], so a whole schema can be declared in
+/// one expression without an intermediate mutable binding:
+///
+/// ```rust,no_run
+/// use zvec_bindings::CollectionSchema;
+///
+/// # fn main() -> zvec_bindings::Result<()> {
+/// let schema = CollectionSchema::builder("docs")
+///     .int64("id")
+///     .string("title")
+///     .vector_fp32("embedding", 768)
+///     .nullable_float("score")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct CollectionSchemaBuilder {
+    name: String,
+    fields: Vec<FieldSchema>,
+}
+
+impl CollectionSchemaBuilder {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Append an arbitrary field, for cases the named helpers below don't
+    /// cover (e.g. a dictionary-encoded string field).
+    pub fn field(mut self, field: FieldSchema) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn bool_(self, name: &str) -> Self {
+        self.field(FieldSchema::bool_(name))
+    }
+
+    pub fn int32(self, name: &str) -> Self {
+        self.field(FieldSchema::int32(name))
+    }
+
+    pub fn int64(self, name: &str) -> Self {
+        self.field(FieldSchema::int64(name))
+    }
+
+    pub fn float(self, name: &str) -> Self {
+        self.field(FieldSchema::float(name))
+    }
+
+    pub fn double(self, name: &str) -> Self {
+        self.field(FieldSchema::double(name))
+    }
+
+    pub fn string(self, name: &str) -> Self {
+        self.field(FieldSchema::string(name))
+    }
+
+    pub fn nullable_bool(self, name: &str) -> Self {
+        let mut field = FieldSchema::bool_(name);
+        field.set_nullable(true);
+        self.field(field)
+    }
+
+    pub fn nullable_int32(self, name: &str) -> Self {
+        let mut field = FieldSchema::int32(name);
+        field.set_nullable(true);
+        self.field(field)
+    }
+
+    pub fn nullable_int64(self, name: &str) -> Self {
+        let mut field = FieldSchema::int64(name);
+        field.set_nullable(true);
+        self.field(field)
+    }
+
+    pub fn nullable_float(self, name: &str) -> Self {
+        let mut field = FieldSchema::float(name);
+        field.set_nullable(true);
+        self.field(field)
+    }
+
+    pub fn nullable_double(self, name: &str) -> Self {
+        let mut field = FieldSchema::double(name);
+        field.set_nullable(true);
+        self.field(field)
+    }
+
+    pub fn nullable_string(self, name: &str) -> Self {
+        let mut field = FieldSchema::string(name);
+        field.set_nullable(true);
+        self.field(field)
+    }
+
+    pub fn vector_fp32(self, name: &str, dimension: u32) -> Self {
+        self.field(VectorSchema::fp32(name, dimension).into())
+    }
+
+    pub fn vector_fp16(self, name: &str, dimension: u32) -> Self {
+        self.field(VectorSchema::fp16(name, dimension).into())
+    }
+
+    /// Add each declared field in order and return the assembled schema, or
+    /// the first error `add_field` reports.
+    pub fn build(self) -> Result<CollectionSchema> {
+        let mut schema = CollectionSchema::new(&self.name);
+        for field in self.fields {
+            schema.add_field(field)?;
+        }
+        Ok(schema)
+    }
+}
+
+/// One sampled field value, as observed by
+/// [`CollectionSchema::infer_from_samples`]. A field absent from a
+/// [`Record`] is treated the same as an explicit [`SampleValue::Null`]: the
+/// field is marked nullable in the inferred schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SampleValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    /// A fixed-length numeric array, inferred as a dense `VectorFp32` field
+    /// with `dimension` set to the array's length.
+    Vector(Vec<f32>),
+}
+
+/// A single sample document used by [`CollectionSchema::infer_from_samples`]:
+/// field name to observed value.
+pub type Record = std::collections::HashMap<String, SampleValue>;
+
+/// The type [`CollectionSchema::infer_from_samples`] has unified a field's
+/// observed [`SampleValue`]s down to, before it becomes a [`FieldSchema`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InferredKind {
+    Bool,
+    Int64,
+    Double,
+    String,
+    Vector(u32),
+}
+
+impl InferredKind {
+    fn into_field_schema(self, field_name: &str) -> FieldSchema {
+        match self {
+            InferredKind::Bool => FieldSchema::bool_(field_name),
+            InferredKind::Int64 => FieldSchema::int64(field_name),
+            InferredKind::Double => FieldSchema::double(field_name),
+            InferredKind::String => FieldSchema::string(field_name),
+            InferredKind::Vector(dimension) => VectorSchema::fp32(field_name, dimension).into(),
+        }
+    }
+}
+
+/// Widen `current` (the type inferred from this field's samples so far, or
+/// `None` on the first non-null sample) with one more observed `value`.
+///
+/// An `Int` widens to `Double` once a `Float` is also seen; anything else
+/// incompatible (e.g. a number alongside a string) falls back to `String`,
+/// except two different vector lengths for the same field, which is a hard
+/// error rather than a silent downgrade, since a vector field's dimension
+/// can't vary per document.
+fn unify_kind(current: Option<InferredKind>, value: &SampleValue) -> Result<InferredKind> {
+    let observed = match value {
+        SampleValue::Null => return Ok(current.unwrap_or(InferredKind::String)),
+        SampleValue::Bool(_) => InferredKind::Bool,
+        SampleValue::Int(_) => InferredKind::Int64,
+        SampleValue::Float(_) => InferredKind::Double,
+        SampleValue::String(_) => InferredKind::String,
+        SampleValue::Vector(v) => InferredKind::Vector(v.len() as u32),
+    };
+
+    let Some(current) = current else {
+        return Ok(observed);
+    };
+
+    match (current, observed) {
+        (a, b) if a == b => Ok(a),
+        (InferredKind::Int64, InferredKind::Double)
+        | (InferredKind::Double, InferredKind::Int64) => Ok(InferredKind::Double),
+        (InferredKind::Vector(expected), InferredKind::Vector(actual)) => {
+            Err(Error::DimensionMismatch {
+                expected: expected as usize,
+                actual: actual as usize,
+            })
+        }
+        _ => Ok(InferredKind::String),
+    }
+}
+
+impl CollectionSchema {
+    /// Infer a schema by scanning `records`, unifying each field's observed
+    /// value types the way a document database infers a schema by sampling.
+    /// `"id"` is treated as the primary key and is never marked nullable
+    /// even if some samples omit it; use
+    /// [`Self::infer_from_samples_with_pk`] to name a different field.
+    pub fn infer_from_samples(name: &str, records: &[Record]) -> Result<Self> {
+        Self::infer_from_samples_with_pk(name, records, "id")
+    }
+
+    /// Like [`Self::infer_from_samples`], but with an explicit primary-key
+    /// field name that is never marked nullable.
+    pub fn infer_from_samples_with_pk(
+        name: &str,
+        records: &[Record],
+        pk_field: &str,
+    ) -> Result<Self> {
+        let mut field_names: Vec<String> = Vec::new();
+        for record in records {
+            for key in record.keys() {
+                if !field_names.contains(key) {
+                    field_names.push(key.clone());
+                }
+            }
+        }
+
+        let mut schema = CollectionSchema::new(name);
+        for field_name in field_names {
+            let mut kind: Option<InferredKind> = None;
+            let mut nullable = false;
+            for record in records {
+                match record.get(&field_name) {
+                    None => nullable = true,
+                    Some(SampleValue::Null) => nullable = true,
+                    Some(value) => kind = Some(unify_kind(kind, value)?),
+                }
+            }
+            let kind = kind.ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "field '{field_name}' has no non-null samples to infer a type from"
+                ))
+            })?;
+
+            let mut field = kind.into_field_schema(&field_name);
+            if field_name != pk_field {
+                field.set_nullable(nullable);
+            }
+            schema.add_field(field)?;
+        }
+        Ok(schema)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorSchema {
+    name: String,
+    data_type: DataType,
+    dimension: u32,
+}
+
+impl VectorSchema {
+    pub fn new(name: impl Into<String>, data_type: DataType, dimension: u32) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            dimension,
+        }
+    }
+
+    pub fn fp32(name: impl Into<String>, dimension: u32) -> Self {
+        Self::new(name, DataType::VectorFp32, dimension)
+    }
+
+    pub fn fp16(name: impl Into<String>, dimension: u32) -> Self {
+        Self::new(name, DataType::VectorFp16, dimension)
+    }
+
+    pub fn sparse_fp32(name: impl Into<String>) -> Self {
+        Self::new(name, DataType::SparseVectorFp32, 0)
+    }
+
+    pub fn sparse_fp32_with_dim(name: impl Into<String>, dimension: u32) -> Self {
+        Self::new(name, DataType::SparseVectorFp32, dimension)
+    }
+
+    pub fn sparse_fp16(name: impl Into<String>) -> Self {
+        Self::new(name, DataType::SparseVectorFp16, 0)
+    }
+
+    pub fn sparse_fp16_with_dim(name: impl Into<String>, dimension: u32) -> Self {
+        Self::new(name, DataType::SparseVectorFp16, dimension)
+    }
+
+    pub fn into_field_schema(self) -> FieldSchema {
+        FieldSchema::new_vector(&self.name, self.data_type, self.dimension)
+    }
+}
+
+impl From<VectorSchema> for FieldSchema {
+    fn from(schema: VectorSchema) -> Self {
+        schema.into_field_schema()
+    }
+}
+
+// SAFETY: These types own their FFI pointers and don't share state.
+// CollectionSchema is typically consumed during collection creation.
+unsafe impl Send for CollectionSchema {}
+unsafe impl Send for FieldSchema {}
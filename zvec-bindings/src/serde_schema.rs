@@ -0,0 +1,137 @@
+//! Serde JSON serialization for [`FieldSchema`] and [`CollectionSchema`],
+//! plus a portable string form for persisting schema definitions to disk,
+//! diffing them in version control, or shipping them between services
+//! without re-declaring fields in code.
+//!
+//! [`FieldSchema`] and [`CollectionSchema`] wrap opaque FFI pointers, so
+//! their `Serialize`/`Deserialize` impls are hand-written here:
+//! serialization reads back through the public accessors (`name`,
+//! `data_type`, `nullable`, `dimension`, `is_dictionary_encoded`), and
+//! deserialization rebuilds the FFI objects via
+//! [`FieldSchema::new`]/[`FieldSchema::new_vector`].
+//! [`VectorSchema`](crate::schema::VectorSchema) holds no FFI state and
+//! derives both traits directly.
+//!
+//! Gate this module behind the `serde` cargo feature.
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::embed::EmbedderMapping;
+use crate::error::{Error, Result};
+use crate::schema::{CollectionSchema, FieldSchema};
+use crate::types::DataType;
+
+#[derive(Serialize, Deserialize)]
+struct FieldSchemaData {
+    name: String,
+    data_type: DataType,
+    dimension: u32,
+    nullable: bool,
+    dictionary_encoded: bool,
+}
+
+impl From<&FieldSchema> for FieldSchemaData {
+    fn from(field: &FieldSchema) -> Self {
+        Self {
+            name: field.name().to_string(),
+            data_type: field.data_type(),
+            dimension: field.dimension(),
+            nullable: field.nullable(),
+            dictionary_encoded: field.is_dictionary_encoded(),
+        }
+    }
+}
+
+impl From<FieldSchemaData> for FieldSchema {
+    fn from(data: FieldSchemaData) -> Self {
+        let mut field = if data.dimension > 0 {
+            FieldSchema::new_vector(&data.name, data.data_type, data.dimension)
+        } else {
+            FieldSchema::new(&data.name, data.data_type)
+        }
+        .dictionary_encoded(data.dictionary_encoded);
+        field.set_nullable(data.nullable);
+        field
+    }
+}
+
+impl Serialize for FieldSchema {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        FieldSchemaData::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldSchema {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        FieldSchemaData::deserialize(deserializer).map(FieldSchema::from)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CollectionSchemaData {
+    name: String,
+    fields: Vec<FieldSchemaData>,
+    embedder_mappings: Vec<EmbedderMapping>,
+}
+
+impl Serialize for CollectionSchema {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let data = CollectionSchemaData {
+            name: self.name().to_string(),
+            fields: self.fields().iter().map(FieldSchemaData::from).collect(),
+            embedder_mappings: self.embedder_mappings().to_vec(),
+        };
+        data.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CollectionSchema {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let data = CollectionSchemaData::deserialize(deserializer)?;
+        let mut schema = CollectionSchema::new(&data.name);
+        for field_data in data.fields {
+            schema
+                .add_field(FieldSchema::from(field_data))
+                .map_err(D::Error::custom)?;
+        }
+        for mapping in data.embedder_mappings {
+            schema
+                .register_embedder(&mapping.source_field, &mapping.target_field)
+                .map_err(D::Error::custom)?;
+        }
+        Ok(schema)
+    }
+}
+
+impl CollectionSchema {
+    /// Encode this schema (fields and
+    /// [`register_embedder`](Self::register_embedder) mappings) as
+    /// pretty-printed JSON, suitable for saving to disk and diffing in
+    /// version control.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| Error::InternalError(format!("failed to encode schema as json: {e}")))
+    }
+
+    /// Decode a schema previously produced by [`Self::to_json`] or
+    /// [`Self::to_string`].
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| Error::InvalidArgument(format!("failed to decode json schema: {e}")))
+    }
+
+    /// Encode this schema as a single-line compact JSON string, for shipping
+    /// between services without the whitespace of [`Self::to_json`].
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> Result<String> {
+        serde_json::to_string(self)
+            .map_err(|e| Error::InternalError(format!("failed to encode schema as json: {e}")))
+    }
+
+    /// Decode a schema previously produced by [`Self::to_string`] or
+    /// [`Self::to_json`].
+    pub fn from_string(s: &str) -> Result<Self> {
+        Self::from_json(s)
+    }
+}
@@ -0,0 +1,35 @@
+//! Client-side bookkeeping for named, immutable snapshots.
+//!
+//! There is no native segment-retention hook in the underlying zvec FFI in
+//! this build, so a [`SnapshotId`] does not yet pin physical index/data
+//! segments the way a table-format snapshot would. What it does give you
+//! today: a stable handle you can list and delete, and a guard that makes
+//! [`Collection::optimize`](crate::collection::Collection::optimize) refuse
+//! to run while any snapshot is held, so a future real implementation has
+//! something to hang segment-pinning off without breaking this API. Deliberately
+//! not provided: a way to open a collection or run a query against the data
+//! visible when a snapshot was captured. The closest thing on offer is
+//! [`VectorQuery::require_snapshot_exists`](crate::query::VectorQuery::require_snapshot_exists),
+//! which only fails the query if `snapshot` has since been deleted - it does
+//! not change which data the query sees.
+//!
+//! **Status: point-in-time reads are not wired up, and not scheduled.**
+//! There is no open path to them in this build: it would need a native
+//! segment-retention/pinning primitive this FFI doesn't expose. Treat
+//! time-travel queries as a non-goal for this build, not a delivered
+//! feature with a narrower name.
+
+use std::time::SystemTime;
+
+/// A handle to a named snapshot captured by
+/// [`Collection::snapshot`](crate::collection::Collection::snapshot).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnapshotId(pub(crate) u64);
+
+/// Metadata recorded for a captured snapshot.
+#[derive(Debug, Clone)]
+pub struct SnapshotInfo {
+    pub id: SnapshotId,
+    pub label: String,
+    pub captured_at: SystemTime,
+}
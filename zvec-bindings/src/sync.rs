@@ -0,0 +1,531 @@
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::collection::Collection;
+use crate::doc::{Doc, DocList, DocMap, WriteResults};
+use crate::embed_queue::EmbeddingsQueueConfig;
+use crate::error::Result;
+use crate::query::{GroupByVectorQuery, GroupResults, HybridQuery, HybridResults, VectorQuery};
+use crate::query_cache::QueryCache;
+use crate::schema::CollectionSchema;
+use crate::IndexParams;
+
+/// Settings for [`SharedCollection::with_auto_index`].
+#[derive(Debug, Clone, Copy)]
+pub struct AutoIndexConfig {
+    /// How long the collection must go without a write before the
+    /// background worker runs `optimize()`. A burst of writes resets this
+    /// timer, so the worker coalesces rapid sequential inserts into one
+    /// optimize pass.
+    pub debounce: Duration,
+    /// How often the worker wakes up to check whether the debounce window
+    /// has elapsed. Lower values notice quiescence sooner at the cost of
+    /// more wakeups.
+    pub poll_interval: Duration,
+}
+
+impl Default for AutoIndexConfig {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(50),
+        }
+    }
+}
+
+struct AutoIndexState {
+    last_write: Option<Instant>,
+    optimized: bool,
+    shutdown: bool,
+}
+
+/// Background worker backing [`SharedCollection::with_auto_index`]: watches
+/// for writes via [`Self::mark_dirty`] and, once `config.debounce` has
+/// passed with no further write, runs `optimize()` on `collection`.
+///
+/// Held behind an `Arc` on [`SharedCollection`] so every clone shares one
+/// worker; it shuts down and joins its thread once the last clone (and
+/// therefore the last `Arc`) drops.
+struct AutoIndexWorker {
+    state: Arc<(Mutex<AutoIndexState>, Condvar)>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AutoIndexWorker {
+    fn spawn(collection: Arc<RwLock<Collection>>, config: AutoIndexConfig) -> Self {
+        let state = Arc::new((
+            Mutex::new(AutoIndexState {
+                last_write: None,
+                optimized: true,
+                shutdown: false,
+            }),
+            Condvar::new(),
+        ));
+        let worker_state = Arc::clone(&state);
+
+        let handle = std::thread::spawn(move || {
+            let (lock, cvar) = &*worker_state;
+            loop {
+                let mut guard = lock.lock().expect("auto-index lock poisoned");
+                loop {
+                    if guard.shutdown {
+                        return;
+                    }
+                    match guard.last_write {
+                        Some(last) if !guard.optimized => {
+                            let elapsed = last.elapsed();
+                            if elapsed >= config.debounce {
+                                break;
+                            }
+                            guard = cvar
+                                .wait_timeout(guard, config.debounce - elapsed)
+                                .expect("auto-index lock poisoned")
+                                .0;
+                        }
+                        _ => {
+                            guard = cvar
+                                .wait_timeout(guard, config.poll_interval)
+                                .expect("auto-index lock poisoned")
+                                .0;
+                        }
+                    }
+                }
+                guard.optimized = true;
+                drop(guard);
+
+                if let Ok(collection) = collection.write() {
+                    let _ = collection.optimize();
+                }
+            }
+        });
+
+        Self {
+            state,
+            handle: Mutex::new(Some(handle)),
+        }
+    }
+
+    /// Mark the collection dirty, resetting the debounce timer.
+    fn mark_dirty(&self) {
+        let (lock, cvar) = &*self.state;
+        let mut guard = lock.lock().expect("auto-index lock poisoned");
+        guard.last_write = Some(Instant::now());
+        guard.optimized = false;
+        cvar.notify_all();
+    }
+}
+
+impl Drop for AutoIndexWorker {
+    fn drop(&mut self) {
+        {
+            let (lock, cvar) = &*self.state;
+            let mut guard = lock.lock().expect("auto-index lock poisoned");
+            guard.shutdown = true;
+            cvar.notify_all();
+        }
+        if let Some(handle) = self.handle.lock().expect("auto-index lock poisoned").take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A thread-safe wrapper around [`Collection`] for concurrent access.
+///
+/// `SharedCollection` uses `Arc<RwLock<Collection>>` internally to provide:
+/// - Concurrent reads (multiple threads can query/fetch simultaneously)
+/// - Exclusive writes (insert/update/delete are serialized)
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zvec_bindings::{create_and_open_shared, SharedCollection, VectorQuery, VectorSchema, CollectionSchema, Doc};
+///
+/// # fn main() -> zvec_bindings::Result<()> {
+/// let mut schema = CollectionSchema::new("my_collection");
+/// schema.add_field(VectorSchema::fp32("embedding", 128).into())?;
+///
+/// let collection = create_and_open_shared("./my_db", schema)?;
+///
+/// // Clone for sharing between threads (cheap - just Arc clone)
+/// let c1 = collection.clone();
+/// let c2 = collection.clone();
+///
+/// // Thread 1: concurrent reads
+/// std::thread::spawn(move || {
+///     let query = VectorQuery::new("embedding").topk(10).vector(&[0.1, 0.2, 0.3, 0.4]).unwrap();
+///     let results = c1.query(query).unwrap();
+/// });
+///
+/// // Thread 2: writes are exclusive
+/// std::thread::spawn(move || {
+///     let mut doc = Doc::id("doc_1");
+///     doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4]).unwrap();
+///     c2.insert(&[doc]).unwrap();
+/// });
+/// # Ok(())
+/// # }
+/// ```
+pub struct SharedCollection {
+    inner: Arc<RwLock<Collection>>,
+    auto_index: Option<Arc<AutoIndexWorker>>,
+    cache: Option<Arc<QueryCache>>,
+}
+
+impl SharedCollection {
+    /// Create a new `SharedCollection` from an existing [`Collection`].
+    pub fn new(collection: Collection) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(collection)),
+            auto_index: None,
+            cache: None,
+        }
+    }
+
+    /// Cache up to `capacity` distinct [`VectorQuery`]/[`GroupByVectorQuery`]
+    /// results (tracked separately), keyed on the query's normalized shape
+    /// (field, topk, filter, vector bytes, metric, required snapshot).
+    ///
+    /// A cache hit returns the previous [`DocList`]/[`GroupResults`] without
+    /// touching the collection; any write - `insert`, `upsert`, `update`,
+    /// `delete`, `delete_by_filter`, `create_index`, `drop_index`, or
+    /// `optimize` - clears the whole cache, since there is no cheap way to
+    /// know which cached queries a given write could have affected.
+    ///
+    /// The cache is shared by every clone of the returned `SharedCollection`,
+    /// same as [`Self::with_auto_index`]'s worker.
+    pub fn with_cache(self, capacity: usize) -> Self {
+        Self {
+            inner: self.inner,
+            auto_index: self.auto_index,
+            cache: Some(Arc::new(QueryCache::new(capacity))),
+        }
+    }
+
+    /// Number of [`Self::query`]/[`Self::group_by_query`] calls served from
+    /// the cache, or `0` if [`Self::with_cache`] was never called.
+    pub fn cache_hits(&self) -> u64 {
+        self.cache.as_ref().map_or(0, |cache| cache.hits())
+    }
+
+    /// Number of [`Self::query`]/[`Self::group_by_query`] calls that missed
+    /// the cache (including every call when [`Self::with_cache`] was never
+    /// called).
+    pub fn cache_misses(&self) -> u64 {
+        self.cache.as_ref().map_or(0, |cache| cache.misses())
+    }
+
+    /// Drop any cached query results, without waiting for the next write.
+    fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate();
+        }
+    }
+
+    /// Spawn a background worker that debounces writes and runs
+    /// `optimize()` once the collection has been quiet for
+    /// `config.debounce`, instead of requiring callers to invoke
+    /// `optimize()` manually. See [`AutoIndexConfig`] for the knobs.
+    ///
+    /// The worker is shared by every clone of the returned
+    /// `SharedCollection` and shuts down, flushing its in-flight optimize
+    /// pass, when the last clone drops.
+    pub fn with_auto_index(self, config: AutoIndexConfig) -> Self {
+        let worker = AutoIndexWorker::spawn(Arc::clone(&self.inner), config);
+        Self {
+            inner: self.inner,
+            auto_index: Some(Arc::new(worker)),
+            cache: self.cache,
+        }
+    }
+
+    /// Mark the collection dirty for the [`AutoIndexConfig`] worker, if one
+    /// is attached via [`Self::with_auto_index`].
+    fn mark_dirty(&self) {
+        if let Some(worker) = &self.auto_index {
+            worker.mark_dirty();
+        }
+    }
+
+    // ===== READ OPERATIONS (take read lock) =====
+
+    /// Execute a vector similarity search query.
+    ///
+    /// Takes a read lock, allowing concurrent queries. If [`Self::with_cache`]
+    /// was called, a result for an equivalent query (see [`Self::with_cache`]
+    /// for what "equivalent" means) is served from the cache instead.
+    pub fn query(&self, query: VectorQuery) -> Result<Arc<DocList>> {
+        if let Some(cache) = &self.cache {
+            let key = query.cache_key();
+            if let Some(hit) = cache.get_query(&key) {
+                return Ok(hit);
+            }
+            let guard = self.inner.read().expect("collection lock poisoned");
+            let result = Arc::new(guard.query(query)?);
+            cache.insert_query(key, Arc::clone(&result));
+            return Ok(result);
+        }
+        let guard = self.inner.read().expect("collection lock poisoned");
+        Ok(Arc::new(guard.query(query)?))
+    }
+
+    /// Execute a grouped vector similarity search query.
+    ///
+    /// Takes a read lock, allowing concurrent queries. If [`Self::with_cache`]
+    /// was called, a result for an equivalent query is served from the cache
+    /// instead.
+    pub fn group_by_query(&self, query: GroupByVectorQuery) -> Result<Arc<GroupResults>> {
+        if let Some(cache) = &self.cache {
+            let key = query.cache_key();
+            if let Some(hit) = cache.get_group_query(&key) {
+                return Ok(hit);
+            }
+            let guard = self.inner.read().expect("collection lock poisoned");
+            let result = Arc::new(guard.group_by_query(query)?);
+            cache.insert_group_query(key, Arc::clone(&result));
+            return Ok(result);
+        }
+        let guard = self.inner.read().expect("collection lock poisoned");
+        Ok(Arc::new(guard.group_by_query(query)?))
+    }
+
+    /// Execute a hybrid dense + sparse + keyword search, fusing the ranked
+    /// legs client-side per [`HybridQuery`]'s chosen
+    /// [`crate::query::FusionMethod`].
+    ///
+    /// Takes a read lock, allowing concurrent queries.
+    pub fn hybrid_query(&self, query: HybridQuery) -> Result<HybridResults> {
+        let guard = self.inner.read().expect("collection lock poisoned");
+        guard.hybrid_query(query)
+    }
+
+    /// Fetch documents by primary key.
+    ///
+    /// Takes a read lock, allowing concurrent fetches.
+    pub fn fetch(&self, pks: &[&str]) -> Result<DocMap> {
+        let guard = self.inner.read().expect("collection lock poisoned");
+        guard.fetch(pks)
+    }
+
+    /// Get the filesystem path where this collection is stored.
+    pub fn path(&self) -> Result<String> {
+        let guard = self.inner.read().expect("collection lock poisoned");
+        guard.path()
+    }
+
+    // ===== WRITE OPERATIONS (take write lock) =====
+
+    /// Insert documents into the collection.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn insert(&self, docs: &[Doc]) -> Result<WriteResults> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.insert(docs);
+        drop(guard);
+        self.mark_dirty();
+        self.invalidate_cache();
+        result
+    }
+
+    /// Insert documents, retrying on a transient failure (see
+    /// [`Error::is_transient`] - internal errors, failed preconditions/lock
+    /// contention) with bounded exponential backoff + jitter, doubling
+    /// `base_backoff` each attempt up to `max_retries` times. A terminal
+    /// error (`InvalidArgument`, `NotFound`, ...) is returned immediately.
+    pub fn insert_with_retry(
+        &self,
+        docs: &[Doc],
+        max_retries: u32,
+        base_backoff: Duration,
+    ) -> Result<WriteResults> {
+        let mut attempt = 0;
+        loop {
+            match self.insert(docs) {
+                Ok(value) => return Ok(value),
+                Err(err) if err.is_transient() && attempt < max_retries => {
+                    let delay = base_backoff * 2u32.pow(attempt)
+                        + Duration::from_millis(retry_jitter_ms(attempt));
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Upsert documents into the collection.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn upsert(&self, docs: &[Doc]) -> Result<WriteResults> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.upsert(docs);
+        drop(guard);
+        self.mark_dirty();
+        self.invalidate_cache();
+        result
+    }
+
+    /// Update existing documents in the collection.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn update(&self, docs: &[Doc]) -> Result<WriteResults> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.update(docs);
+        drop(guard);
+        self.mark_dirty();
+        self.invalidate_cache();
+        result
+    }
+
+    /// Delete documents by primary key.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn delete(&self, pks: &[&str]) -> Result<WriteResults> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.delete(pks);
+        drop(guard);
+        self.mark_dirty();
+        self.invalidate_cache();
+        result
+    }
+
+    /// Delete documents matching a filter expression.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn delete_by_filter(&self, filter: &str) -> Result<()> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.delete_by_filter(filter);
+        drop(guard);
+        self.mark_dirty();
+        self.invalidate_cache();
+        result
+    }
+
+    /// Create an index on a vector field.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn create_index(&self, column_name: &str, params: IndexParams) -> Result<()> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.create_index(column_name, params);
+        drop(guard);
+        self.invalidate_cache();
+        result
+    }
+
+    /// Drop an index from a column.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn drop_index(&self, column_name: &str) -> Result<()> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.drop_index(column_name);
+        drop(guard);
+        self.invalidate_cache();
+        result
+    }
+
+    /// Build (or rebuild) a client-side keyword index over `field` for use
+    /// as the keyword leg of a [`HybridQuery`].
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn create_text_index(&self, field: &str) -> Result<()> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        guard.create_text_index(field)
+    }
+
+    /// Stop maintaining the client-side keyword index over `field`.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn drop_text_index(&self, field: &str) {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        guard.drop_text_index(field)
+    }
+
+    /// Configure how `insert`/`upsert`/`update` batch and retry embedder
+    /// calls; see [`EmbeddingsQueueConfig`] for what each knob controls.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn configure_embeddings_queue(&self, config: EmbeddingsQueueConfig) {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        guard.configure_embeddings_queue(config)
+    }
+
+    /// Optimize the collection for better search performance.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn optimize(&self) -> Result<()> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        let result = guard.optimize();
+        drop(guard);
+        self.invalidate_cache();
+        result
+    }
+
+    /// Flush pending writes to disk.
+    ///
+    /// Takes a write lock, exclusive access.
+    pub fn flush(&self) -> Result<()> {
+        let guard = self.inner.write().expect("collection lock poisoned");
+        guard.flush()
+    }
+
+    /// Destroy the collection and delete all data.
+    ///
+    /// Consumes self. This method should only be called when no other
+    /// clones of this `SharedCollection` exist.
+    pub fn destroy(self) -> Result<()> {
+        // Drop (and join) the auto-index worker first: it holds its own
+        // clone of `inner` for as long as it runs, which would otherwise
+        // make `try_unwrap` below fail even with no other `SharedCollection`
+        // clones outstanding.
+        drop(self.auto_index);
+        match Arc::try_unwrap(self.inner) {
+            Ok(lock) => {
+                let collection = lock.into_inner().expect("collection lock poisoned");
+                collection.destroy()
+            }
+            Err(_) => Err(crate::error::Error::InvalidArgument(
+                "cannot destroy SharedCollection: other clones exist".into(),
+            )),
+        }
+    }
+}
+
+impl Clone for SharedCollection {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            auto_index: self.auto_index.clone(),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+/// A small deterministic jitter so retries from concurrent callers don't
+/// all wake up at once, without pulling in a random-number crate. Mirrors
+/// `embed_queue::jitter_ms`.
+fn retry_jitter_ms(attempt: u32) -> u64 {
+    (u64::from(attempt).wrapping_mul(2_654_435_761) % 50) + 1
+}
+
+/// Create and open a new collection wrapped in a [`SharedCollection`].
+pub fn create_and_open_shared<P: AsRef<Path>>(
+    path: P,
+    schema: CollectionSchema,
+) -> Result<SharedCollection> {
+    let collection = Collection::create_and_open(path, schema)?;
+    Ok(SharedCollection::new(collection))
+}
+
+/// Open an existing collection wrapped in a [`SharedCollection`].
+pub fn open_shared<P: AsRef<Path>>(path: P) -> Result<SharedCollection> {
+    let collection = Collection::open(path)?;
+    Ok(SharedCollection::new(collection))
+}
+
+/// Create an in-memory collection (see [`Collection::in_memory`]) wrapped in
+/// a [`SharedCollection`].
+pub fn create_shared_in_memory(schema: CollectionSchema) -> Result<SharedCollection> {
+    let collection = Collection::in_memory(schema)?;
+    Ok(SharedCollection::new(collection))
+}
@@ -0,0 +1,103 @@
+//! Element-wise arithmetic and normalization on dense float vectors.
+//!
+//! These operate on plain `&mut [f32]`/`&[f32]` slices; [`Doc`] exposes them
+//! as convenience methods that read a vector field with
+//! [`Doc::get_vector`](crate::doc::Doc::get_vector), transform it in place,
+//! and write it back with
+//! [`Doc::set_vector`](crate::doc::Doc::set_vector). Every dense vector
+//! field in this crate round-trips through the fp32 FFI entry points
+//! regardless of its declared `DataType`, so no separate fp16/fp64 code
+//! paths are needed here.
+
+use crate::doc::Doc;
+use crate::error::{Error, Result};
+
+/// Add `scalar` to every element of `vector`.
+pub fn add_scalar(vector: &mut [f32], scalar: f32) {
+    for x in vector.iter_mut() {
+        *x += scalar;
+    }
+}
+
+/// Multiply every element of `vector` by `factor`.
+pub fn scale(vector: &mut [f32], factor: f32) {
+    for x in vector.iter_mut() {
+        *x *= factor;
+    }
+}
+
+/// Add `other` to `vector` element-wise.
+///
+/// # Errors
+///
+/// Returns [`Error::DimensionMismatch`] if the two slices have different
+/// lengths.
+pub fn add_vector(vector: &mut [f32], other: &[f32]) -> Result<()> {
+    if vector.len() != other.len() {
+        return Err(Error::DimensionMismatch {
+            expected: vector.len(),
+            actual: other.len(),
+        });
+    }
+    for (x, y) in vector.iter_mut().zip(other) {
+        *x += y;
+    }
+    Ok(())
+}
+
+/// Scale `vector` to unit L2 norm in place.
+///
+/// A zero vector is left unchanged rather than dividing by zero.
+pub fn l2_normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        scale(vector, 1.0 / norm);
+    }
+}
+
+impl Doc {
+    /// Add `scalar` to every element of the dense vector field `field`.
+    pub fn add_scalar(&mut self, field: &str, scalar: f32) -> Result<()> {
+        let mut vector = self
+            .get_vector(field)
+            .ok_or_else(|| Error::FieldNotFound(field.to_string()))?;
+        add_scalar(&mut vector, scalar);
+        self.set_vector(field, &vector)
+    }
+
+    /// Multiply every element of the dense vector field `field` by `factor`.
+    pub fn scale(&mut self, field: &str, factor: f32) -> Result<()> {
+        let mut vector = self
+            .get_vector(field)
+            .ok_or_else(|| Error::FieldNotFound(field.to_string()))?;
+        scale(&mut vector, factor);
+        self.set_vector(field, &vector)
+    }
+
+    /// Add `other` to the dense vector field `field`, element-wise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if `other`'s length doesn't
+    /// match the stored vector's length.
+    pub fn add_vector(&mut self, field: &str, other: &[f32]) -> Result<()> {
+        let mut vector = self
+            .get_vector(field)
+            .ok_or_else(|| Error::FieldNotFound(field.to_string()))?;
+        add_vector(&mut vector, other)?;
+        self.set_vector(field, &vector)
+    }
+
+    /// Scale the dense vector field `field` to unit L2 norm.
+    ///
+    /// Pairs naturally with [`MetricType::Cosine`](crate::types::MetricType::Cosine)
+    /// and [`MetricType::Ip`](crate::types::MetricType::Ip): normalize once
+    /// on write and an inner-product index behaves like cosine similarity.
+    pub fn l2_normalize(&mut self, field: &str) -> Result<()> {
+        let mut vector = self
+            .get_vector(field)
+            .ok_or_else(|| Error::FieldNotFound(field.to_string()))?;
+        l2_normalize(&mut vector);
+        self.set_vector(field, &vector)
+    }
+}
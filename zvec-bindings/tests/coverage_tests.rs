@@ -1,10 +1,87 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 use tempfile::TempDir;
+use zvec_bindings::dictionary::FrontCodedDictionary;
+use zvec_bindings::embed::Embedder;
 use zvec_bindings::{
-    create_and_open, open, Collection, CollectionSchema, DataType, Doc, FieldSchema,
-    GroupByVectorQuery, IndexParams, IndexType, MetricType, QuantizeType, VectorQuery,
+    create_and_open, create_in_memory, open, Collection, CollectionSchema, Compatibility,
+    Conversion, DataType, Doc, Error, FieldChange, FieldSchema, FieldValue, GroupByVectorQuery,
+    IndexParams, IndexType, MetricType, QuantizeType, Record, SampleValue, VectorQuery,
     VectorSchema,
 };
 
+/// Test [`Embedder`] that maps each text to its byte length repeated across
+/// the target dimension, so assertions can check the embedding ran without
+/// depending on a real model.
+struct LengthEmbedder {
+    dimension: usize,
+}
+
+impl Embedder for LengthEmbedder {
+    fn embed(&self, texts: &[&str]) -> zvec_bindings::Result<Vec<Vec<f32>>> {
+        Ok(texts
+            .iter()
+            .map(|t| vec![t.len() as f32; self.dimension])
+            .collect())
+    }
+}
+
+/// Like [`LengthEmbedder`] but counts how many texts it was actually asked
+/// to embed, so tests can assert the content-digest cache skipped calls for
+/// unchanged text.
+struct CountingEmbedder {
+    dimension: usize,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Embedder for CountingEmbedder {
+    fn embed(&self, texts: &[&str]) -> zvec_bindings::Result<Vec<Vec<f32>>> {
+        self.calls.fetch_add(texts.len(), Ordering::SeqCst);
+        Ok(texts
+            .iter()
+            .map(|t| vec![t.len() as f32; self.dimension])
+            .collect())
+    }
+}
+
+/// Records the size of every `embed` call it receives, so tests can assert
+/// [`zvec_bindings::EmbeddingsQueueConfig::max_batch_chars`] split a large
+/// batch into several smaller ones.
+struct BatchRecordingEmbedder {
+    dimension: usize,
+    batch_sizes: Arc<std::sync::Mutex<Vec<usize>>>,
+}
+
+impl Embedder for BatchRecordingEmbedder {
+    fn embed(&self, texts: &[&str]) -> zvec_bindings::Result<Vec<Vec<f32>>> {
+        self.batch_sizes.lock().unwrap().push(texts.len());
+        Ok(texts
+            .iter()
+            .map(|t| vec![t.len() as f32; self.dimension])
+            .collect())
+    }
+}
+
+/// Test [`Embedder`] that only implements [`Embedder::embed_sparse`], mapping
+/// each text to a fixed pair of indices with the text length and a constant
+/// as values, so assertions can check a round trip through a sparse vector
+/// field without depending on a real sparse model.
+struct SparseLengthEmbedder;
+
+impl Embedder for SparseLengthEmbedder {
+    fn embed(&self, texts: &[&str]) -> zvec_bindings::Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|_| Vec::new()).collect())
+    }
+
+    fn embed_sparse(&self, texts: &[&str]) -> zvec_bindings::Result<Vec<(Vec<u32>, Vec<f32>)>> {
+        Ok(texts
+            .iter()
+            .map(|t| (vec![0, 1], vec![t.len() as f32, 1.0]))
+            .collect())
+    }
+}
+
 fn tempdir() -> zvec_bindings::Result<TempDir> {
     tempfile::tempdir().map_err(|e| zvec_bindings::Error::InternalError(e.to_string()))
 }
@@ -118,6 +195,62 @@ mod coverage_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_doc_get_sparse_vector_round_trips() -> zvec_bindings::Result<()> {
+        let mut doc = Doc::id("test");
+        doc.set_sparse_vector("sparse_field", &[1, 5, 10], &[0.1, 0.2, 0.3])?;
+
+        let (indices, values) = doc
+            .get_sparse_vector("sparse_field")
+            .expect("should be set");
+        assert_eq!(indices, vec![1, 5, 10]);
+        assert_eq!(values, vec![0.1, 0.2, 0.3]);
+
+        assert!(doc.get_sparse_vector("nonexistent").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doc_ref_get_sparse_vector_round_trips() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::sparse_fp32("sparse").into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_sparse_vector("sparse", &[1, 5, 10], &[0.1, 0.2, 0.3])?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        let doc = fetched.get("doc_1").expect("Document should exist");
+
+        let (indices, values) = doc.get_sparse_vector("sparse").expect("should be set");
+        assert_eq!(indices, vec![1, 5, 10]);
+        assert_eq!(values, vec![0.1, 0.2, 0.3]);
+
+        assert!(doc.get_sparse_vector("nonexistent").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_vector_into_reuses_buffer() -> zvec_bindings::Result<()> {
+        let mut doc = Doc::id("test");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+
+        let mut buf = Vec::new();
+        assert!(doc.get_vector_into("embedding", &mut buf));
+        assert_eq!(buf, vec![0.1, 0.2, 0.3, 0.4]);
+
+        assert!(!doc.get_vector_into("nonexistent", &mut buf));
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_doc_builder_chaining() -> zvec_bindings::Result<()> {
         let doc = Doc::id("test")
@@ -161,6 +294,43 @@ mod coverage_tests {
         assert_eq!(fs.dimension(), 128);
     }
 
+    #[test]
+    fn test_field_schema_dictionary_encoded() {
+        let fs = FieldSchema::string("url").dictionary_encoded(true);
+        assert!(fs.is_dictionary_encoded());
+
+        let fs = FieldSchema::string("plain");
+        assert!(!fs.is_dictionary_encoded());
+    }
+
+    #[test]
+    fn test_front_coded_dictionary_roundtrip() {
+        let strings: Vec<String> = vec![
+            "apple",
+            "application",
+            "apply",
+            "banana",
+            "band",
+            "bandana",
+            "cherry",
+            "citrus",
+            "date",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let dict = FrontCodedDictionary::build(&strings);
+        assert_eq!(dict.len(), strings.len());
+
+        for s in &strings {
+            let id = dict.id_for(s).expect("string should be present");
+            assert_eq!(dict.string_at(id).as_deref(), Some(s.as_str()));
+        }
+
+        assert!(dict.id_for("missing").is_none());
+    }
+
     #[test]
     fn test_field_schema_nullable() {
         let mut fs = FieldSchema::string("test");
@@ -208,6 +378,224 @@ mod coverage_tests {
         let _ = schema.name();
     }
 
+    #[test]
+    fn test_collection_schema_builder() -> zvec_bindings::Result<()> {
+        let schema = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .vector_fp32("embedding", 768)
+            .nullable_float("score")
+            .build()?;
+
+        assert_eq!(schema.name(), "docs");
+        assert_eq!(schema.field_count(), 4);
+
+        let id_field = schema.field_at(0).unwrap();
+        assert_eq!(id_field.name(), "id");
+        assert_eq!(id_field.data_type(), DataType::Int64);
+
+        let title_field = schema.field_at(1).unwrap();
+        assert_eq!(title_field.name(), "title");
+        assert_eq!(title_field.data_type(), DataType::String);
+
+        let embedding_field = schema.field_at(2).unwrap();
+        assert_eq!(embedding_field.name(), "embedding");
+        assert_eq!(embedding_field.data_type(), DataType::VectorFp32);
+        assert_eq!(embedding_field.dimension(), 768);
+
+        let score_field = schema.field_at(3).unwrap();
+        assert_eq!(score_field.name(), "score");
+        assert_eq!(score_field.data_type(), DataType::Float);
+        assert!(score_field.nullable());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_schema_infer_from_samples() -> zvec_bindings::Result<()> {
+        let mut doc1 = Record::new();
+        doc1.insert("id".to_string(), SampleValue::String("a".to_string()));
+        doc1.insert("count".to_string(), SampleValue::Int(3));
+        doc1.insert(
+            "embedding".to_string(),
+            SampleValue::Vector(vec![0.1, 0.2, 0.3]),
+        );
+        doc1.insert("name".to_string(), SampleValue::String("alpha".to_string()));
+
+        let mut doc2 = Record::new();
+        doc2.insert("id".to_string(), SampleValue::String("b".to_string()));
+        // "count" is a float here, so it should widen to Double overall.
+        doc2.insert("count".to_string(), SampleValue::Float(4.5));
+        doc2.insert(
+            "embedding".to_string(),
+            SampleValue::Vector(vec![0.4, 0.5, 0.6]),
+        );
+        // "name" is absent from this sample, so it should be nullable.
+
+        let schema = CollectionSchema::infer_from_samples("docs", &[doc1, doc2])?;
+        assert_eq!(schema.name(), "docs");
+        assert_eq!(schema.field_count(), 4);
+
+        let fields: std::collections::HashMap<String, _> = schema
+            .fields()
+            .into_iter()
+            .map(|f| (f.name().to_string(), f))
+            .collect();
+
+        let id_field = &fields["id"];
+        assert_eq!(id_field.data_type(), DataType::String);
+        assert!(!id_field.nullable());
+
+        let count_field = &fields["count"];
+        assert_eq!(count_field.data_type(), DataType::Double);
+
+        let embedding_field = &fields["embedding"];
+        assert_eq!(embedding_field.data_type(), DataType::VectorFp32);
+        assert_eq!(embedding_field.dimension(), 3);
+
+        let name_field = &fields["name"];
+        assert_eq!(name_field.data_type(), DataType::String);
+        assert!(name_field.nullable());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_schema_infer_from_samples_vector_dimension_mismatch() {
+        let mut doc1 = Record::new();
+        doc1.insert("id".to_string(), SampleValue::String("a".to_string()));
+        doc1.insert(
+            "embedding".to_string(),
+            SampleValue::Vector(vec![0.1, 0.2, 0.3]),
+        );
+
+        let mut doc2 = Record::new();
+        doc2.insert("id".to_string(), SampleValue::String("b".to_string()));
+        doc2.insert("embedding".to_string(), SampleValue::Vector(vec![0.1, 0.2]));
+
+        let result = CollectionSchema::infer_from_samples("docs", &[doc1, doc2]);
+        assert!(matches!(result, Err(Error::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_collection_schema_equality() -> zvec_bindings::Result<()> {
+        let build = || {
+            CollectionSchema::builder("docs")
+                .int64("id")
+                .vector_fp32("embedding", 4)
+                .build()
+        };
+
+        assert_eq!(build()?, build()?);
+
+        let mut renamed = build()?;
+        renamed.add_field(FieldSchema::string("extra"))?;
+        assert_ne!(build()?, renamed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_schema_diff() -> zvec_bindings::Result<()> {
+        let old = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .vector_fp32("embedding", 4)
+            .build()?;
+
+        let new = CollectionSchema::builder("docs")
+            .int64("id")
+            .nullable_string("title")
+            .vector_fp32("embedding", 8)
+            .nullable_float("score")
+            .build()?;
+
+        let diff = new.diff(&old);
+        assert_eq!(diff.added, vec!["score".to_string()]);
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.changed.len(), 2);
+
+        let title_changes = &diff
+            .changed
+            .iter()
+            .find(|(name, _)| name == "title")
+            .unwrap()
+            .1;
+        assert_eq!(
+            title_changes,
+            &vec![FieldChange::Nullable {
+                from: false,
+                to: true
+            }]
+        );
+
+        let embedding_changes = &diff
+            .changed
+            .iter()
+            .find(|(name, _)| name == "embedding")
+            .unwrap()
+            .1;
+        assert_eq!(
+            embedding_changes,
+            &vec![FieldChange::Dimension { from: 4, to: 8 }]
+        );
+
+        assert!(!diff.is_empty());
+        assert!(old.diff(&old).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_schema_compatible_with() -> zvec_bindings::Result<()> {
+        let deployed = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .build()?;
+
+        assert_eq!(
+            deployed.compatible_with(&deployed),
+            Compatibility::Identical
+        );
+
+        let add_nullable_field = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .nullable_float("score")
+            .build()?;
+        assert_eq!(
+            add_nullable_field.compatible_with(&deployed),
+            Compatibility::BackwardCompatible
+        );
+
+        let remove_field = CollectionSchema::builder("docs").int64("id").build()?;
+        assert_eq!(
+            remove_field.compatible_with(&deployed),
+            Compatibility::ForwardCompatible
+        );
+
+        let change_type = CollectionSchema::builder("docs")
+            .int64("id")
+            .int64("title")
+            .build()?;
+        assert_eq!(
+            change_type.compatible_with(&deployed),
+            Compatibility::Breaking
+        );
+
+        let add_required_field = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .string("author")
+            .build()?;
+        assert_eq!(
+            add_required_field.compatible_with(&deployed),
+            Compatibility::Breaking
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_index_params_types() {
         let params = IndexParams::hnsw(16, 200, MetricType::L2, QuantizeType::Undefined);
@@ -227,6 +615,7 @@ mod coverage_tests {
     fn test_query_builder_all_options() -> zvec_bindings::Result<()> {
         let query = VectorQuery::new("embedding")
             .topk(100)
+            .offset(20)
             .filter("count > 10")
             .include_vector(true)
             .include_doc_id(true)
@@ -237,6 +626,86 @@ mod coverage_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_query_page_sets_offset_and_topk() -> zvec_bindings::Result<()> {
+        let query = VectorQuery::new("embedding")
+            .page(1, 20)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+
+        drop(query);
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_ef_search_and_search_param_builders() -> zvec_bindings::Result<()> {
+        let query = VectorQuery::new("embedding")
+            .topk(10)
+            .ef_search(256)
+            .search_param("n_probe", "16")
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+
+        drop(query);
+
+        let group_query = GroupByVectorQuery::new("embedding")
+            .group_by("category")
+            .ef_search(256)
+            .search_param("n_probe", "16")
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+
+        drop(group_query);
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_ef_search_recall_on_clustered_dataset() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let params = IndexParams::hnsw(16, 200, MetricType::L2, QuantizeType::Undefined);
+        collection.create_index("embedding", params)?;
+
+        // Several tight clusters far apart from each other, so a narrow HNSW
+        // candidate list (low ef_search) is more likely to miss the true
+        // nearest neighbor than a wide one.
+        let mut docs = Vec::new();
+        for cluster in 0..20 {
+            let base = (cluster * 1000) as f32;
+            for i in 0..5 {
+                let mut doc = Doc::id(format!("doc_{cluster}_{i}"));
+                let jitter = i as f32 * 0.01;
+                doc.set_vector("embedding", &[base + jitter, base, base, base])?;
+                docs.push(doc);
+            }
+        }
+        collection.insert(&docs)?;
+
+        let target = &[0.0, 0.0, 0.0, 0.0];
+
+        let narrow = VectorQuery::new("embedding")
+            .topk(1)
+            .ef_search(1)
+            .vector(target)?;
+        let narrow_results = collection.query(narrow)?;
+
+        let wide = VectorQuery::new("embedding")
+            .topk(1)
+            .ef_search(256)
+            .vector(target)?;
+        let wide_results = collection.query(wide)?;
+
+        assert_eq!(narrow_results.len(), 1);
+        assert_eq!(wide_results.len(), 1);
+        // A wider candidate list can only find a result at least as good
+        // (lower L2 distance) as a narrower one.
+        assert!(wide_results.get(0).unwrap().score() <= narrow_results.get(0).unwrap().score());
+
+        Ok(())
+    }
+
     #[test]
     fn test_query_sparse_vector() -> zvec_bindings::Result<()> {
         let query = VectorQuery::new("sparse_embedding")
@@ -348,6 +817,101 @@ mod coverage_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_batch_mixes_insert_upsert_and_delete() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut existing = Doc::id("doc_1");
+        existing.set_vector("embedding", &[0.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[existing])?;
+
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+
+        let mut updated = Doc::id("doc_1");
+        updated.set_vector("embedding", &[0.5, 0.6, 0.7, 0.8])?;
+
+        let results = collection
+            .batch()
+            .insert(vec![doc2])
+            .upsert(vec![updated])
+            .delete(&["doc_2"])
+            .commit()?;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 3);
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert!(fetched.get("doc_1").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_batch_stops_at_first_error() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+
+        // Updating a document that doesn't exist yet should fail, so the
+        // batch stops before the following insert ever runs.
+        let mut missing_update = Doc::id("does_not_exist");
+        missing_update.set_vector("embedding", &[0.0, 0.0, 0.0, 0.0])?;
+
+        let result = collection
+            .batch()
+            .update(vec![missing_update])
+            .insert(vec![doc1])
+            .commit();
+        assert!(result.is_err());
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert!(fetched.get("doc_1").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_insert_chunks_and_reports_progress() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let docs: Vec<Doc> = (0..5)
+            .map(|i| {
+                let mut doc = Doc::id(format!("doc_{i}"));
+                doc.set_vector("embedding", &[i as f32, 0.0, 0.0, 0.0])
+                    .unwrap();
+                doc
+            })
+            .collect();
+
+        let mut progress_calls = Vec::new();
+        let opts = zvec_bindings::BulkOptions {
+            chunk_size: 2,
+            flush_every_n_chunks: Some(1),
+            max_retries: 1,
+        };
+        let results = collection.bulk_insert(&docs, opts, |done, total| {
+            progress_calls.push((done, total));
+        })?;
+
+        assert_eq!(results.len(), 5);
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 5);
+        assert_eq!(progress_calls, vec![(2, 5), (4, 5), (5, 5)]);
+
+        let fetched = collection.fetch(&["doc_0", "doc_4"])?;
+        assert!(fetched.get("doc_0").is_some());
+        assert!(fetched.get("doc_4").is_some());
+
+        Ok(())
+    }
+
     #[test]
     fn test_doc_list_iteration() -> zvec_bindings::Result<()> {
         let dir = tempdir()?;
@@ -435,10 +999,16 @@ mod coverage_tests {
         let vec = doc.get_vector("embedding").expect("Should have vector");
         assert_eq!(vec.len(), 4);
 
+        let mut buf = Vec::new();
+        assert!(doc.get_vector_into("embedding", &mut buf));
+        assert_eq!(buf, vec);
+
         assert!(doc.get_string("nonexistent").is_none());
         assert!(doc.get_int64("nonexistent").is_none());
         assert!(doc.get_float("nonexistent").is_none());
         assert!(doc.get_vector("nonexistent").is_none());
+        assert!(!doc.get_vector_into("nonexistent", &mut buf));
+        assert!(doc.get_sparse_vector("nonexistent").is_none());
 
         Ok(())
     }
@@ -669,41 +1239,1531 @@ mod coverage_tests {
     }
 
     #[test]
-    fn test_error_types() {
-        let err = zvec_bindings::Error::NotFound("test".to_string());
-        assert!(format!("{}", err).contains("test"));
-
-        let err = zvec_bindings::Error::AlreadyExists("test".to_string());
-        assert!(format!("{}", err).contains("test"));
-
-        let err = zvec_bindings::Error::NotSupported("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+    fn test_doc_vector_arithmetic() -> zvec_bindings::Result<()> {
+        let mut doc = Doc::id("test_doc");
+        doc.set_vector("embedding", &[1.0, 2.0, 3.0, 4.0])?;
 
-        let err = zvec_bindings::Error::PermissionDenied("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+        doc.add_scalar("embedding", 1.0)?;
+        assert_eq!(
+            doc.get_vector("embedding").unwrap(),
+            vec![2.0, 3.0, 4.0, 5.0]
+        );
 
-        let err = zvec_bindings::Error::FailedPrecondition("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+        doc.scale("embedding", 2.0)?;
+        assert_eq!(
+            doc.get_vector("embedding").unwrap(),
+            vec![4.0, 6.0, 8.0, 10.0]
+        );
 
-        let err = zvec_bindings::Error::Unknown("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+        doc.add_vector("embedding", &[1.0, 1.0, 1.0, 1.0])?;
+        assert_eq!(
+            doc.get_vector("embedding").unwrap(),
+            vec![5.0, 7.0, 9.0, 11.0]
+        );
 
-        let err = zvec_bindings::Error::CollectionNotFound("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+        assert!(matches!(
+            doc.add_vector("embedding", &[1.0]),
+            Err(zvec_bindings::Error::DimensionMismatch { .. })
+        ));
 
-        let err = zvec_bindings::Error::IndexNotFound("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+        doc.l2_normalize("embedding")?;
+        let normalized = doc.get_vector("embedding").unwrap();
+        let norm: f32 = normalized.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
 
-        let err = zvec_bindings::Error::FieldNotFound("test".to_string());
-        assert!(format!("{}", err).contains("test"));
+        Ok(())
     }
-}
 
-#[cfg(feature = "sync")]
-mod sync_tests {
-    use super::*;
-    use std::thread;
-    use std::time::Duration;
+    #[test]
+    fn test_hybrid_query_rrf_fusion() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.5, 0.6, 0.7, 0.8])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let query = zvec_bindings::HybridQuery::new()
+            .dense("embedding", &[0.1, 0.2, 0.3, 0.4])
+            .topk(5);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+        assert!(results.get(0).unwrap().fused_score() > 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_query_extra_dense_leg_fuses_a_second_field() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(VectorSchema::fp32("embedding2", 4).into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_vector("embedding2", &[0.0, 0.0, 0.0, 1.0])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.0, 1.0, 0.0, 0.0])?;
+        doc2.set_vector("embedding2", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc1, doc2])?;
+
+        // doc_2 ranks first on both the `embedding` leg and the `embedding2`
+        // leg, so it tops the fused ranking.
+        let query = zvec_bindings::HybridQuery::new()
+            .dense("embedding", &[0.0, 1.0, 0.0, 0.0])
+            .extra_dense("embedding2", &[1.0, 0.0, 0.0, 0.0])
+            .topk(5);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+        assert_eq!(results.get(0).unwrap().doc().pk(), "doc_2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_results_combines_dense_and_sparse_queries() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("dense", 4).into())?;
+        schema.add_field(VectorSchema::sparse_fp32("sparse").into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("dense", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_sparse_vector("sparse", &[0, 1], &[1.0, 0.5])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("dense", &[0.0, 1.0, 0.0, 0.0])?;
+        doc2.set_sparse_vector("sparse", &[2, 3], &[1.0, 0.5])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let dense_query = VectorQuery::new("dense")
+            .topk(10)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+        let dense_results = collection.query(dense_query)?;
+
+        let sparse_query = VectorQuery::new("sparse")
+            .topk(10)
+            .sparse_vector(&[0, 1], &[1.0, 0.5])?;
+        let sparse_results = collection.query(sparse_query)?;
+
+        let fused = zvec_bindings::fuse_results(&[&dense_results, &sparse_results], None, 10);
+        assert_eq!(fused.len(), 2);
+        // doc_1 ranks first on both legs, so it should fuse to the top.
+        assert_eq!(fused[0].0, "doc_1");
+        assert!(fused[0].1 > fused[1].1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_results_respects_topk() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut docs = Vec::new();
+        for i in 0..5 {
+            let mut doc = Doc::id(format!("doc_{i}"));
+            doc.set_vector("embedding", &[i as f32, 0.0, 0.0, 0.0])?;
+            docs.push(doc);
+        }
+        collection.insert(&docs)?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(10)
+            .vector(&[4.0, 0.0, 0.0, 0.0])?;
+        let results = collection.query(query)?;
+
+        let fused = zvec_bindings::fuse_results(&[&results], Some(60.0), 2);
+        assert_eq!(fused.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_results_preserves_doc_access() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.0, 1.0, 0.0, 0.0])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(10)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+        let results = collection.query(query)?;
+
+        let fused = zvec_bindings::FusedResults::fuse(vec![results], None, 10);
+        assert_eq!(fused.len(), 2);
+        let hit = fused.get(0).unwrap();
+        assert_eq!(hit.doc().pk(), "doc_1");
+        assert!(hit.fused_score() > 0.0);
+        assert_eq!(fused.iter().count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_hit_ref_score_details_breaks_down_per_list() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("dense", 4).into())?;
+        schema.add_field(VectorSchema::sparse_fp32("sparse").into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("dense", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_sparse_vector("sparse", &[0, 1], &[1.0, 0.5])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("dense", &[0.0, 1.0, 0.0, 0.0])?;
+        doc2.set_sparse_vector("sparse", &[2, 3], &[1.0, 0.5])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let dense_results = collection.query(
+            VectorQuery::new("dense")
+                .topk(10)
+                .vector(&[1.0, 0.0, 0.0, 0.0])?,
+        )?;
+        let sparse_results = collection.query(
+            VectorQuery::new("sparse")
+                .topk(10)
+                .sparse_vector(&[0, 1], &[1.0, 0.5])?,
+        )?;
+
+        let fused =
+            zvec_bindings::FusedResults::fuse(vec![dense_results, sparse_results], None, 10);
+        let hit = fused.get(0).unwrap();
+        assert_eq!(hit.doc().pk(), "doc_1");
+
+        let details = hit.score_details();
+        assert_eq!(details.components.len(), 2);
+        assert!(details.components.iter().any(|c| c.source == "list0"));
+        assert!(details.components.iter().any(|c| c.source == "list1"));
+        assert_eq!(details.normalized_score, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_fused_query_combines_two_legs() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("dense", 4).into())?;
+        schema.add_field(VectorSchema::sparse_fp32("sparse").into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("dense", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_sparse_vector("sparse", &[0, 1], &[1.0, 0.5])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("dense", &[0.0, 1.0, 0.0, 0.0])?;
+        doc2.set_sparse_vector("sparse", &[2, 3], &[1.0, 0.5])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let dense_leg = VectorQuery::new("dense")
+            .topk(10)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+        let sparse_leg = VectorQuery::new("sparse")
+            .topk(10)
+            .sparse_vector(&[0, 1], &[1.0, 0.5])?;
+
+        let fused = collection.fused_query(vec![dense_leg, sparse_leg], None, 10)?;
+        assert_eq!(fused.len(), 2);
+        let hit = fused.get(0).unwrap();
+        assert_eq!(hit.doc().pk(), "doc_1");
+        assert_eq!(hit.score_details().components.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_fused_query_respects_topk() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.0, 1.0, 0.0, 0.0])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let leg = VectorQuery::new("embedding")
+            .topk(10)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+
+        let fused = collection.fused_query(vec![leg], None, 1)?;
+        assert_eq!(fused.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_multi_vector_query_weights_fields() -> zvec_bindings::Result<()> {
+        use zvec_bindings::MultiVectorQuery;
+
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding_a", 4).into())?;
+        schema.add_field(VectorSchema::fp32("embedding_b", 4).into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding_a", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_vector("embedding_b", &[0.0, 0.0, 0.0, 1.0])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding_a", &[0.0, 0.0, 0.0, 1.0])?;
+        doc2.set_vector("embedding_b", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc1, doc2])?;
+
+        // embedding_a strongly favors doc_1 and is weighted much higher, so
+        // doc_1 should win the fused ranking even though embedding_b favors
+        // doc_2.
+        let query = MultiVectorQuery::new()
+            .field("embedding_a", &[1.0, 0.0, 0.0, 0.0], 10.0)
+            .field("embedding_b", &[1.0, 0.0, 0.0, 0.0], 0.1)
+            .topk(10);
+        let fused = collection.multi_vector_query(query)?;
+        assert_eq!(fused.len(), 2);
+        assert_eq!(fused.get(0).unwrap().doc().pk(), "doc_1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_multi_vector_query_respects_filter_and_topk() -> zvec_bindings::Result<()> {
+        use zvec_bindings::MultiVectorQuery;
+
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding_a", 4).into())?;
+        schema.add_field(VectorSchema::fp32("embedding_b", 4).into())?;
+        schema.add_field(FieldSchema::int64("count"))?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding_a", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_vector("embedding_b", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_int64("count", 1)?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding_a", &[1.0, 0.0, 0.0, 0.0])?;
+        doc2.set_vector("embedding_b", &[1.0, 0.0, 0.0, 0.0])?;
+        doc2.set_int64("count", 100)?;
+        collection.insert(&[doc1, doc2])?;
+
+        let query = MultiVectorQuery::new()
+            .field("embedding_a", &[1.0, 0.0, 0.0, 0.0], 1.0)
+            .field("embedding_b", &[1.0, 0.0, 0.0, 0.0], 1.0)
+            .filter("count > 10")
+            .topk(10);
+        let fused = collection.multi_vector_query(query)?;
+        assert_eq!(fused.len(), 1);
+        assert_eq!(fused.get(0).unwrap().doc().pk(), "doc_2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_embed_on_insert() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::string("text"))?;
+        schema.register_embedder("text", "embedding")?;
+
+        let collection = create_and_open(&path, schema)?;
+        collection.set_embedder("embedding", LengthEmbedder { dimension: 4 });
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_string("text", "hi")?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(
+            fetched.get("doc_1").unwrap().get_vector("embedding"),
+            Some(vec![2.0, 2.0, 2.0, 2.0])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doc_set_text_embeds_directly_on_target_field() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+        collection.set_embedder("embedding", LengthEmbedder { dimension: 4 });
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_text("embedding", "hi");
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(
+            fetched.get("doc_1").unwrap().get_vector("embedding"),
+            Some(vec![2.0, 2.0, 2.0, 2.0])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doc_set_text_requires_registered_embedder() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_text("embedding", "hi");
+        let result = collection.insert(&[doc]);
+        assert!(matches!(
+            result,
+            Err(zvec_bindings::Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_embedder_embed_sparse_defaults_to_not_supported() {
+        let result = LengthEmbedder { dimension: 4 }.embed_sparse(&["hi"]);
+        assert!(matches!(result, Err(zvec_bindings::Error::NotSupported(_))));
+    }
+
+    #[test]
+    fn test_doc_set_text_embeds_sparse_field_directly() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::sparse_fp32("sparse").into())?;
+        let collection = create_and_open(&path, schema)?;
+        collection.set_embedder("sparse", SparseLengthEmbedder);
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_text("sparse", "hi");
+        collection.insert(&[doc])?;
+
+        let query = VectorQuery::new("sparse")
+            .topk(1)
+            .sparse_vector(&[0, 1], &[2.0, 1.0])?;
+        let results = collection.query(query)?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.iter().next().unwrap().pk(), "doc_1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_embed_sparse_field_via_mapping() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::sparse_fp32("sparse").into())?;
+        schema.add_field(FieldSchema::string("text"))?;
+        schema.register_embedder("text", "sparse")?;
+
+        let collection = create_and_open(&path, schema)?;
+        collection.set_embedder("sparse", SparseLengthEmbedder);
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_string("text", "hi")?;
+        collection.insert(&[doc])?;
+
+        let query = VectorQuery::new("sparse")
+            .topk(1)
+            .sparse_vector(&[0, 1], &[2.0, 1.0])?;
+        let results = collection.query(query)?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.iter().next().unwrap().pk(), "doc_1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_embedding_cache_skips_unchanged_text() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+        let calls = Arc::new(AtomicUsize::new(0));
+        collection.set_embedder(
+            "embedding",
+            CountingEmbedder {
+                dimension: 4,
+                calls: Arc::clone(&calls),
+            },
+        );
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_text("embedding", "hi");
+        collection.insert(&[doc1])?;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Same text on a different doc should hit the cache, not the embedder.
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_text("embedding", "hi");
+        collection.insert(&[doc2])?;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Different text is a genuine cache miss.
+        let mut doc3 = Doc::id("doc_3");
+        doc3.set_text("embedding", "hello");
+        collection.insert(&[doc3])?;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_embeddings_for_digests_returns_cached_vectors() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+        collection.set_embedder("embedding", LengthEmbedder { dimension: 4 });
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_text("embedding", "hi");
+        collection.insert(&[doc])?;
+
+        let digest = zvec_bindings::Digest::compute("embedding", "hi", "");
+        let cached = collection.embeddings_for_digests(&[digest.clone()]);
+        assert_eq!(cached.get(&digest), Some(&vec![2.0, 2.0, 2.0, 2.0]));
+
+        let miss = zvec_bindings::Digest::compute("embedding", "nope", "");
+        assert!(collection.embeddings_for_digests(&[miss]).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_embeddings_queue_config_splits_large_batches() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+        let batch_sizes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        collection.set_embedder(
+            "embedding",
+            BatchRecordingEmbedder {
+                dimension: 4,
+                batch_sizes: Arc::clone(&batch_sizes),
+            },
+        );
+        collection.configure_embeddings_queue(zvec_bindings::EmbeddingsQueueConfig {
+            max_batch_chars: 5,
+            ..Default::default()
+        });
+
+        let docs: Vec<Doc> = ["aaaaa", "bbbbb", "ccccc"]
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let mut doc = Doc::id(format!("doc_{i}"));
+                doc.set_text("embedding", text);
+                doc
+            })
+            .collect();
+        collection.insert(&docs)?;
+
+        // Each 5-char text alone fills the 5-char budget, so every text
+        // lands in its own batch rather than one combined call.
+        assert_eq!(*batch_sizes.lock().unwrap(), vec![1, 1, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_memory_collection_supports_full_surface_and_has_no_path() -> zvec_bindings::Result<()>
+    {
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        let collection = Collection::in_memory(schema)?;
+
+        assert!(collection.path().is_err());
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc])?;
+
+        let mut update_doc = Doc::id("doc_1");
+        update_doc.set_vector("embedding", &[0.5, 0.6, 0.7, 0.8])?;
+        collection.update(&[update_doc])?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(10)
+            .vector(&[0.5, 0.6, 0.7, 0.8])?;
+        let results = collection.query(query)?;
+        assert_eq!(results.len(), 1);
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(fetched.len(), 1);
+
+        collection.delete(&["doc_1"])?;
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(fetched.len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_in_memory_matches_create_and_open_constructor_shape() -> zvec_bindings::Result<()>
+    {
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        let collection = create_in_memory(schema)?;
+
+        assert!(collection.path().is_err());
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(fetched.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_query_text_embeds_with_registered_embedder() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::string("text"))?;
+        schema.register_embedder("text", "embedding")?;
+
+        let collection = create_and_open(&path, schema)?;
+        collection.set_embedder("embedding", LengthEmbedder { dimension: 4 });
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[3.0, 3.0, 3.0, 3.0])?;
+        collection.insert(&[doc])?;
+
+        let query = VectorQuery::text("embedding", "abc").topk(5);
+        let results = collection.query(query)?;
+        assert!(!results.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_vector_query_text_embeds_with_registered_embedder() -> zvec_bindings::Result<()>
+    {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::string("category"))?;
+        schema.add_field(FieldSchema::string("text"))?;
+        schema.register_embedder("text", "embedding")?;
+
+        let collection = create_and_open(&path, schema)?;
+        collection.set_embedder("embedding", LengthEmbedder { dimension: 4 });
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[3.0, 3.0, 3.0, 3.0])?;
+        doc.set_string("category", "a")?;
+        collection.insert(&[doc])?;
+
+        let query = GroupByVectorQuery::text("embedding", "abc")
+            .group_by("category")
+            .group_count(5)
+            .group_topk(10);
+        let results = collection.group_by_query(query)?;
+        assert!(!results.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_explained_score_details() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.5, 0.6, 0.7, 0.8])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(5)
+            .metric(MetricType::Cosine)
+            .explain(true)
+            .vector(&[0.1, 0.2, 0.3, 0.4])?;
+        let results = collection.query_explained(query)?;
+        assert!(!results.is_empty());
+
+        let (_, details) = results.get(0).unwrap();
+        assert_eq!(details.rank, 0);
+        assert_eq!(details.metric, MetricType::Cosine);
+        assert_eq!(details.filter_matched, None);
+        assert!(details.components.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_explained_score_details_reports_filter_matched() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::int64("count"))?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        doc1.set_int64("count", 42)?;
+        collection.insert(&[doc1])?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(5)
+            .filter("count > 10")
+            .explain(true)
+            .vector(&[0.1, 0.2, 0.3, 0.4])?;
+        let results = collection.query_explained(query)?;
+        assert!(!results.is_empty());
+
+        let (_, details) = results.get(0).unwrap();
+        assert_eq!(details.filter_matched, Some(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_offset_pages_through_results() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let docs: Vec<Doc> = (0..10)
+            .map(|i| {
+                let mut doc = Doc::id(format!("doc_{i}"));
+                let v = i as f32;
+                doc.set_vector("embedding", &[v, v, v, v]).unwrap();
+                doc
+            })
+            .collect();
+        collection.insert(&docs)?;
+
+        let full = VectorQuery::new("embedding")
+            .topk(10)
+            .offset(0)
+            .vector(&[0.0, 0.0, 0.0, 0.0])?;
+        let full_results = collection.query(full)?;
+        assert_eq!(full_results.len(), 10);
+        let expected_page: Vec<String> = full_results
+            .iter()
+            .skip(5)
+            .take(5)
+            .map(|d| d.pk().to_string())
+            .collect();
+
+        let paged = VectorQuery::new("embedding")
+            .topk(5)
+            .offset(5)
+            .vector(&[0.0, 0.0, 0.0, 0.0])?;
+        let paged_results = collection.query(paged)?;
+        let actual_page: Vec<String> = paged_results.iter().map(|d| d.pk().to_string()).collect();
+
+        assert_eq!(actual_page, expected_page);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_explained_requires_explain_flag() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(5)
+            .vector(&[0.1, 0.2, 0.3, 0.4])?;
+        assert!(matches!(
+            collection.query_explained(query),
+            Err(zvec_bindings::Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_score_details_is_an_explain_alias() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc])?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(5)
+            .with_score_details(true)
+            .vector(&[0.1, 0.2, 0.3, 0.4])?;
+        let results = collection.query_explained(query)?;
+        assert!(!results.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_query_keyword_leg_requires_text_index() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let query = zvec_bindings::HybridQuery::new().keyword("body", "fusion ranking");
+        assert!(matches!(
+            collection.hybrid_query(query),
+            Err(zvec_bindings::Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_query_keyword_bm25_ranking() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::string("body"))?;
+        let collection = create_and_open(&path, schema)?;
+        collection.create_text_index("body")?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        doc1.set_string(
+            "body",
+            "reciprocal rank fusion combines dense and sparse search",
+        )?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.5, 0.6, 0.7, 0.8])?;
+        doc2.set_string("body", "completely unrelated gardening tips")?;
+        collection.insert(&[doc1, doc2])?;
+
+        let query = zvec_bindings::HybridQuery::new()
+            .keyword("body", "reciprocal rank fusion")
+            .topk(5);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+        assert_eq!(results.get(0).unwrap().doc().pk(), "doc_1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_query_dense_keyword_semantic_ratio() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::string("body"))?;
+        let collection = create_and_open(&path, schema)?;
+        collection.create_text_index("body")?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        doc1.set_string("body", "vector search fuses dense and sparse rankings")?;
+        collection.insert(&[doc1])?;
+
+        let query = zvec_bindings::HybridQuery::new()
+            .dense("embedding", &[0.1, 0.2, 0.3, 0.4])
+            .keyword("body", "dense rankings")
+            .semantic_ratio(0.5)
+            .topk(5)
+            .explain(true);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+
+        let details = results.get(0).unwrap().score_details();
+        assert_eq!(details.components.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_query_explain_components() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc1])?;
+
+        let query = zvec_bindings::HybridQuery::new()
+            .dense("embedding", &[0.1, 0.2, 0.3, 0.4])
+            .topk(5)
+            .explain(true);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+
+        let details = results.get(0).unwrap().score_details();
+        assert_eq!(details.rank, 0);
+        assert_eq!(details.components.len(), 1);
+        assert_eq!(details.components[0].source, "dense");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hybrid_query_weighted_rrf_fusion() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(VectorSchema::sparse_fp32("keywords").into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        doc1.set_sparse_vector("keywords", &[0, 1], &[1.0, 1.0])?;
+        collection.insert(&[doc1])?;
+
+        let query = zvec_bindings::HybridQuery::new()
+            .dense("embedding", &[1.0, 0.0, 0.0, 0.0])
+            .sparse("keywords", &[0, 1], &[1.0, 1.0])?
+            .k(60)
+            .dense_weight(2.0)
+            .sparse_weight(0.5)
+            .topk(5)
+            .explain(true);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+
+        let details = results.get(0).unwrap().score_details();
+        let dense = details
+            .components
+            .iter()
+            .find(|c| c.source == "dense")
+            .expect("dense component present");
+        assert_eq!(dense.weight, 2.0);
+        let sparse = details
+            .components
+            .iter()
+            .find(|c| c.source == "sparse")
+            .expect("sparse component present");
+        assert_eq!(sparse.weight, 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_lifecycle() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let snapshot_id = collection.snapshot("before_bulk_load")?;
+        assert_eq!(collection.list_snapshots().len(), 1);
+        assert_eq!(collection.list_snapshots()[0].label, "before_bulk_load");
+
+        assert!(matches!(
+            collection.optimize(),
+            Err(zvec_bindings::Error::FailedPrecondition(_))
+        ));
+
+        collection.delete_snapshot(snapshot_id)?;
+        assert!(collection.list_snapshots().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_require_snapshot_exists_rejects_unknown_snapshot() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc])?;
+
+        let unknown = collection.snapshot("kept_alive")?;
+        collection.delete_snapshot(unknown)?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(5)
+            .vector(&[0.1, 0.2, 0.3, 0.4])?
+            .require_snapshot_exists(unknown);
+        assert!(matches!(
+            collection.query(query),
+            Err(zvec_bindings::Error::NotFound(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_types() {
+        let err = zvec_bindings::Error::NotFound("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::AlreadyExists("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::NotSupported("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::PermissionDenied("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::FailedPrecondition("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::Unknown("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::CollectionNotFound("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::IndexNotFound("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+
+        let err = zvec_bindings::Error::FieldNotFound("test".to_string());
+        assert!(format!("{}", err).contains("test"));
+    }
+
+    #[test]
+    fn test_error_status_code_and_transience() {
+        let direct = zvec_bindings::Error::InvalidArgument("bad arg".to_string());
+        assert_eq!(direct.status_code(), None);
+        assert!(!direct.is_transient());
+
+        let wrapped = zvec_bindings::Error::WithCode {
+            source: Box::new(zvec_bindings::Error::InternalError("boom".to_string())),
+            code: 13,
+        };
+        assert_eq!(wrapped.status_code(), Some(13));
+        assert!(wrapped.is_transient());
+        assert!(format!("{}", wrapped).contains("boom"));
+
+        let wrapped_terminal = zvec_bindings::Error::WithCode {
+            source: Box::new(zvec_bindings::Error::NotFound("missing".to_string())),
+            code: 5,
+        };
+        assert_eq!(wrapped_terminal.status_code(), Some(5));
+        assert!(!wrapped_terminal.is_transient());
+
+        let failed_precondition = zvec_bindings::Error::FailedPrecondition("locked".to_string());
+        assert!(failed_precondition.is_transient());
+    }
+
+    #[test]
+    fn test_conversion_from_name() -> zvec_bindings::Result<()> {
+        assert_eq!(Conversion::from_name("int")?, Conversion::Int);
+        assert_eq!(Conversion::from_name("integer")?, Conversion::Int);
+        assert_eq!(Conversion::from_name("float")?, Conversion::Float);
+        assert_eq!(Conversion::from_name("bool")?, Conversion::Bool);
+        assert_eq!(Conversion::from_name("boolean")?, Conversion::Bool);
+        assert_eq!(Conversion::from_name("string")?, Conversion::String);
+        assert_eq!(Conversion::from_name("bytes")?, Conversion::String);
+        assert_eq!(Conversion::from_name("timestamp")?, Conversion::Timestamp);
+        assert_eq!(
+            Conversion::from_name("timestamp_fmt:%Y/%m/%d")?,
+            Conversion::TimestampFmt("%Y/%m/%d".to_string())
+        );
+
+        assert!(matches!(
+            Conversion::from_name("nope"),
+            Err(zvec_bindings::Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversion_convert_scalar_types() -> zvec_bindings::Result<()> {
+        assert_eq!(Conversion::Int.convert("42")?, FieldValue::Int64(42));
+        assert_eq!(Conversion::Float.convert("1.5")?, FieldValue::Double(1.5));
+        assert_eq!(Conversion::Bool.convert("yes")?, FieldValue::Bool(true));
+        assert_eq!(Conversion::Bool.convert("0")?, FieldValue::Bool(false));
+        assert_eq!(
+            Conversion::String.convert(" hello ")?,
+            FieldValue::String("hello".to_string())
+        );
+
+        assert!(Conversion::Int.convert("not a number").is_err());
+        assert!(Conversion::Bool.convert("maybe").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversion_convert_timestamps() -> zvec_bindings::Result<()> {
+        assert_eq!(
+            Conversion::Timestamp.convert("1970-01-01T00:00:00Z")?,
+            FieldValue::Timestamp(0)
+        );
+        assert_eq!(
+            Conversion::Timestamp.convert("2024-01-15T09:30:00Z")?,
+            FieldValue::Timestamp(1705311000)
+        );
+        // A `+01:00` offset should be subtracted back to UTC.
+        assert_eq!(
+            Conversion::Timestamp.convert("2024-01-15T10:30:00+01:00")?,
+            FieldValue::Timestamp(1705311000)
+        );
+
+        let fmt = Conversion::from_name("timestamp_fmt:%Y/%m/%d %H:%M:%S")?;
+        assert_eq!(
+            fmt.convert("2024/01/15 09:30:00")?,
+            FieldValue::Timestamp(1705311000)
+        );
+
+        assert!(Conversion::Timestamp.convert("not a timestamp").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doc_set_converted_round_trips_through_collection() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::int64("count"))?;
+        schema.add_field(FieldSchema::int64("created_at"))?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        doc.set_converted("count", Conversion::Int.convert("42")?)?;
+        doc.set_converted(
+            "created_at",
+            Conversion::Timestamp.convert("1970-01-01T00:00:01Z")?,
+        )?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        let doc = fetched.get("doc_1").expect("Document should exist");
+        assert_eq!(doc.get_int64("count"), Some(42));
+        assert_eq!(doc.get_int64("created_at"), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_and_open_with_options_local_backend() -> zvec_bindings::Result<()> {
+        use zvec_bindings::{CollectionOptions, StorageBackend};
+
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+
+        let options = CollectionOptions::new().backend(StorageBackend::Local)?;
+        let collection = Collection::create_and_open_with_options(&path, schema, &options)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert!(fetched.get("doc_1").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_backend_s3_rejects_empty_fields() {
+        use zvec_bindings::{CollectionOptions, StorageBackend};
+
+        let result = CollectionOptions::new().backend(StorageBackend::S3 {
+            bucket: String::new(),
+            prefix: "docs/".to_string(),
+            endpoint: "https://s3.example.com".to_string(),
+            region: "us-east-1".to_string(),
+        });
+        assert!(matches!(
+            result,
+            Err(zvec_bindings::Error::InvalidArgument(_))
+        ));
+    }
+}
+
+#[cfg(feature = "polars")]
+mod polars_tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_map_into_dataframe() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        let collection = create_and_open(&path, schema)?;
+
+        let mut doc = Doc::id("test_doc");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["test_doc"])?;
+        let mut dataframe_schema = CollectionSchema::new("test");
+        dataframe_schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        let df = fetched.into_dataframe(&dataframe_schema)?;
+        assert_eq!(df.height(), 1);
+        assert!(df.column("pk").is_ok());
+        assert!(df.column("embedding").is_ok());
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_tests {
+    use super::*;
+    use std::sync::Arc;
+    use zvec_bindings::{AsyncCollection, SyncCollection};
+
+    #[tokio::test]
+    async fn test_async_insert_and_fetch() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = Arc::new(create_collection(&path)?);
+
+        let mut doc = Doc::id("test_doc");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        AsyncCollection::insert(&collection, vec![doc]).await?;
+
+        let fetched = AsyncCollection::fetch(&collection, vec!["test_doc".to_string()]).await?;
+        assert!(fetched.get("test_doc").is_some());
+
+        AsyncCollection::flush(&collection).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_query_and_create_index() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = Arc::new(create_collection(&path)?);
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        AsyncCollection::insert(&collection, vec![doc]).await?;
+
+        AsyncCollection::create_index(
+            &collection,
+            "embedding".to_string(),
+            IndexParams::flat(MetricType::L2, QuantizeType::Undefined),
+        )
+        .await?;
+
+        let query = VectorQuery::new("embedding")
+            .topk(10)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+        let results = AsyncCollection::query(&collection, query).await?;
+        assert!(!results.is_empty());
+
+        let group_query = GroupByVectorQuery::new("embedding")
+            .group_by("embedding")
+            .group_count(5)
+            .group_topk(10)
+            .vector(&[1.0, 0.0, 0.0, 0.0])?;
+        let grouped = AsyncCollection::group_by_query(&collection, group_query).await?;
+        assert!(!grouped.is_empty());
+
+        AsyncCollection::optimize(&collection).await?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sync_collection_trait() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc = Doc::id("test_doc");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        SyncCollection::insert(&collection, &[doc])?;
+
+        let fetched = SyncCollection::fetch(&collection, &["test_doc"])?;
+        assert!(fetched.get("test_doc").is_some());
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_vector_view() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc = Doc::id("test_doc");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["test_doc"])?;
+        let doc_ref = fetched.get("test_doc").unwrap();
+        let view = doc_ref.vector_view("embedding").unwrap();
+        assert_eq!(view.len(), 4);
+        assert!((view[0] - 0.1).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doc_map_vectors_matrix() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        let mut doc1 = Doc::id("doc_1");
+        doc1.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        let mut doc2 = Doc::id("doc_2");
+        doc2.set_vector("embedding", &[0.5, 0.6, 0.7, 0.8])?;
+        collection.insert(&[doc1, doc2])?;
+
+        let fetched = collection.fetch(&["doc_1", "doc_2"])?;
+        let matrix = fetched.vectors_matrix("embedding");
+        assert_eq!(matrix.shape(), &[2, 4]);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "arrow")]
+mod arrow_tests {
+    use super::*;
+    use arrow::array::{
+        ArrayRef, FixedSizeListArray, Float32Array, Int32Array, ListArray, StringArray, StructArray,
+    };
+    use arrow::datatypes::Field;
+    use arrow::record_batch::RecordBatch;
+    use std::sync::Arc;
+    use zvec_bindings::arrow::create_and_open_from_parquet;
+
+    fn dense_batch() -> RecordBatch {
+        let pk = Arc::new(StringArray::from(vec!["doc_1", "doc_2"])) as ArrayRef;
+        let item_field = Arc::new(Field::new(
+            "item",
+            arrow::datatypes::DataType::Float32,
+            false,
+        ));
+        let values = Float32Array::from(vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]);
+        let embedding =
+            Arc::new(FixedSizeListArray::try_new(item_field, 4, Arc::new(values), None).unwrap())
+                as ArrayRef;
+
+        RecordBatch::try_from_iter(vec![("pk", pk), ("embedding", embedding)]).unwrap()
+    }
+
+    #[test]
+    fn test_export_parquet_is_not_supported() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let db_path = dir.path().join("test_db");
+        let collection = create_collection(&db_path)?;
+
+        let results = collection.insert_record_batch(&dense_batch())?;
+        assert_eq!(results.len(), 2);
+
+        let parquet_path = dir.path().join("export.parquet");
+        assert!(matches!(
+            collection.export_parquet(&parquet_path),
+            Err(zvec_bindings::Error::NotSupported(_))
+        ));
+        assert!(!parquet_path.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_vector_struct_column_mapping() {
+        let indices_item = Arc::new(Field::new("item", arrow::datatypes::DataType::Int32, false));
+        let values_item = Arc::new(Field::new(
+            "item",
+            arrow::datatypes::DataType::Float32,
+            false,
+        ));
+
+        let indices = ListArray::try_new(
+            indices_item,
+            arrow::buffer::OffsetBuffer::new(vec![0, 2].into()),
+            Arc::new(Int32Array::from(vec![1, 5])),
+            None,
+        )
+        .unwrap();
+        let values = ListArray::try_new(
+            values_item,
+            arrow::buffer::OffsetBuffer::new(vec![0, 2].into()),
+            Arc::new(Float32Array::from(vec![0.25, 0.75])),
+            None,
+        )
+        .unwrap();
+
+        let sparse = StructArray::from(vec![
+            (
+                Arc::new(Field::new(
+                    "indices",
+                    arrow::datatypes::DataType::List(Arc::new(Field::new(
+                        "item",
+                        arrow::datatypes::DataType::Int32,
+                        false,
+                    ))),
+                    false,
+                )),
+                Arc::new(indices) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new(
+                    "values",
+                    arrow::datatypes::DataType::List(Arc::new(Field::new(
+                        "item",
+                        arrow::datatypes::DataType::Float32,
+                        false,
+                    ))),
+                    false,
+                )),
+                Arc::new(values) as ArrayRef,
+            ),
+        ]);
+
+        let pk = Arc::new(StringArray::from(vec!["doc_1"])) as ArrayRef;
+        let batch =
+            RecordBatch::try_from_iter(vec![("pk", pk), ("sparse", Arc::new(sparse) as ArrayRef)])
+                .unwrap();
+
+        assert_eq!(batch.num_rows(), 1);
+        assert!(matches!(
+            batch
+                .schema()
+                .field_with_name("sparse")
+                .unwrap()
+                .data_type(),
+            arrow::datatypes::DataType::Struct(_)
+        ));
+    }
+
+    #[test]
+    fn test_create_and_open_from_parquet_missing_file() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test_db");
+        let mut schema = CollectionSchema::new("test");
+        schema
+            .add_field(VectorSchema::fp32("embedding", 4).into())
+            .unwrap();
+
+        let missing_parquet = dir.path().join("does_not_exist.parquet");
+        let result = create_and_open_from_parquet(&db_path, schema, &missing_parquet);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_collection_schema_export_import_arrow_round_trip() -> zvec_bindings::Result<()> {
+        use zvec_bindings::arrow::import_arrow;
+
+        let mut schema = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .vector_fp32("embedding", 8)
+            .nullable_float("score")
+            .build()?;
+        schema.add_field(FieldSchema::bool_("published"))?;
+
+        let ffi_schema = schema.export_arrow()?;
+        let imported = import_arrow(&ffi_schema)?;
+
+        assert_eq!(imported.name(), "docs");
+        assert_eq!(imported.field_count(), schema.field_count());
+
+        let id_field = imported.field_at(0).unwrap();
+        assert_eq!(id_field.name(), "id");
+        assert_eq!(id_field.data_type(), DataType::Int64);
+        assert!(!id_field.nullable());
+
+        let title_field = imported.field_at(1).unwrap();
+        assert_eq!(title_field.name(), "title");
+        assert_eq!(title_field.data_type(), DataType::String);
+
+        let embedding_field = imported.field_at(2).unwrap();
+        assert_eq!(embedding_field.name(), "embedding");
+        assert_eq!(embedding_field.data_type(), DataType::VectorFp32);
+        assert_eq!(embedding_field.dimension(), 8);
+
+        let score_field = imported.field_at(3).unwrap();
+        assert_eq!(score_field.name(), "score");
+        assert_eq!(score_field.data_type(), DataType::Float);
+        assert!(score_field.nullable());
+
+        let published_field = imported.field_at(4).unwrap();
+        assert_eq!(published_field.name(), "published");
+        assert_eq!(published_field.data_type(), DataType::Bool);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_schema_export_import_arrow_sparse_vector_round_trip(
+    ) -> zvec_bindings::Result<()> {
+        use zvec_bindings::arrow::import_arrow;
+
+        let mut schema = CollectionSchema::new("docs");
+        schema.add_field(FieldSchema::new_vector(
+            "weights",
+            DataType::SparseVectorFp32,
+            0,
+        ))?;
+
+        let ffi_schema = schema.export_arrow()?;
+        let imported = import_arrow(&ffi_schema)?;
+
+        let weights_field = imported.field_at(0).unwrap();
+        assert_eq!(weights_field.name(), "weights");
+        assert_eq!(weights_field.data_type(), DataType::SparseVectorFp32);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sync")]
+mod sync_tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
     use zvec_bindings::{create_and_open_shared, open_shared, SharedCollection};
 
     fn create_shared_collection(path: &std::path::Path) -> zvec_bindings::Result<SharedCollection> {
@@ -813,6 +2873,37 @@ mod sync_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shared_collection_auto_index_optimizes_after_debounce() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection =
+            create_shared_collection(&path)?.with_auto_index(zvec_bindings::AutoIndexConfig {
+                debounce: Duration::from_millis(20),
+                poll_interval: Duration::from_millis(5),
+            });
+
+        for i in 0..3 {
+            let mut doc = Doc::id(format!("doc_{i}"));
+            doc.set_vector("embedding", &[i as f32 * 0.1, 0.0, 0.0, 0.0])?;
+            collection.insert(&[doc])?;
+        }
+
+        // The worker should coalesce the burst above into one optimize
+        // pass once writes go quiet; give it a few debounce windows to run.
+        thread::sleep(Duration::from_millis(200));
+
+        // The collection should still be fully usable: auto-index doesn't
+        // block or corrupt concurrent reads/writes.
+        let query = VectorQuery::new("embedding")
+            .topk(10)
+            .vector(&[0.1, 0.0, 0.0, 0.0])?;
+        let results = collection.query(query)?;
+        assert_eq!(results.len(), 3);
+
+        Ok(())
+    }
+
     #[test]
     fn test_shared_collection_fetch() -> zvec_bindings::Result<()> {
         let dir = tempdir()?;
@@ -829,6 +2920,31 @@ mod sync_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shared_collection_hybrid_query_keyword() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        schema.add_field(FieldSchema::string("body"))?;
+        let collection = create_and_open_shared(&path, schema)?;
+        collection.create_text_index("body")?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[0.1, 0.2, 0.3, 0.4])?;
+        doc.set_string("body", "reciprocal rank fusion")?;
+        collection.insert(&[doc])?;
+
+        let query = zvec_bindings::HybridQuery::new()
+            .keyword("body", "reciprocal fusion")
+            .topk(5);
+        let results = collection.hybrid_query(query)?;
+        assert!(!results.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_shared_collection_open() -> zvec_bindings::Result<()> {
         let dir = tempdir()?;
@@ -897,4 +3013,313 @@ mod sync_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_shared_in_memory_collection_has_no_path() -> zvec_bindings::Result<()> {
+        let mut schema = CollectionSchema::new("test");
+        schema.add_field(VectorSchema::fp32("embedding", 4).into())?;
+        let collection = zvec_bindings::create_shared_in_memory(schema)?;
+
+        assert!(collection.path().is_err());
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(fetched.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_collection_insert_with_retry_succeeds_without_retrying(
+    ) -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_shared_collection(&path)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert_with_retry(&[doc], 3, Duration::from_millis(1))?;
+
+        let fetched = collection.fetch(&["doc_1"])?;
+        assert_eq!(fetched.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_collection_insert_with_retry_returns_terminal_error_immediately(
+    ) -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_shared_collection(&path)?;
+
+        // No vector set on a required vector field: the native library
+        // rejects this immediately, and an `InvalidArgument` (if one ever
+        // reached us via `check_status`) is terminal, not transient - no
+        // retry delay should be observed.
+        let doc = Doc::id("doc_1");
+        let result = collection.insert_with_retry(&[doc], 3, Duration::from_secs(5));
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_collection_cache_hit_on_repeated_query() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_shared_collection(&path)?.with_cache(10);
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        let query = || {
+            VectorQuery::new("embedding")
+                .topk(10)
+                .vector(&[1.0, 0.0, 0.0, 0.0])
+                .unwrap()
+        };
+
+        let first = collection.query(query())?;
+        assert_eq!(collection.cache_misses(), 1);
+        assert_eq!(collection.cache_hits(), 0);
+
+        let second = collection.query(query())?;
+        assert_eq!(collection.cache_hits(), 1);
+        assert_eq!(second.len(), first.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_collection_cache_miss_on_different_query() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_shared_collection(&path)?.with_cache(10);
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        collection.query(
+            VectorQuery::new("embedding")
+                .topk(10)
+                .vector(&[1.0, 0.0, 0.0, 0.0])?,
+        )?;
+        collection.query(
+            VectorQuery::new("embedding")
+                .topk(5)
+                .vector(&[1.0, 0.0, 0.0, 0.0])?,
+        )?;
+
+        assert_eq!(collection.cache_misses(), 2);
+        assert_eq!(collection.cache_hits(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_collection_cache_invalidated_on_write() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_shared_collection(&path)?.with_cache(10);
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        let query = || {
+            VectorQuery::new("embedding")
+                .topk(10)
+                .vector(&[1.0, 0.0, 0.0, 0.0])
+                .unwrap()
+        };
+
+        let before = collection.query(query())?;
+        assert_eq!(before.len(), 1);
+
+        let mut doc = Doc::id("doc_2");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        let after = collection.query(query())?;
+        assert_eq!(after.len(), 2);
+        // The post-write query is a fresh miss, not a stale cached hit.
+        assert_eq!(collection.cache_misses(), 2);
+        assert_eq!(collection.cache_hits(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cbor")]
+mod cbor_tests {
+    use super::*;
+    use zvec_bindings::{ExportOptions, IndexSpec};
+
+    #[test]
+    fn test_export_import_snapshot_round_trip() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let src_path = dir.path().join("src_db");
+        let collection = create_collection(&src_path)?;
+
+        let mut doc_1 = Doc::id("doc_1");
+        doc_1.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        let mut doc_2 = Doc::id("doc_2");
+        doc_2.set_vector("embedding", &[0.0, 1.0, 0.0, 0.0])?;
+        collection.insert(&[doc_1, doc_2])?;
+
+        collection.create_index(
+            "embedding",
+            IndexParams::flat(MetricType::L2, QuantizeType::Undefined),
+        )?;
+
+        let snapshot_path = dir.path().join("snapshot.cbor");
+        collection.export_snapshot(
+            &["doc_1", "doc_2"],
+            &snapshot_path,
+            ExportOptions::default(),
+        )?;
+        assert!(snapshot_path.exists());
+
+        let dst_path = dir.path().join("dst_db");
+        let imported = Collection::import_snapshot(dst_path, snapshot_path)?;
+
+        let fetched = imported.fetch(&["doc_1", "doc_2"])?;
+        let doc_1 = fetched.get("doc_1").expect("doc_1 should be imported");
+        assert_eq!(
+            doc_1.get_vector("embedding").unwrap(),
+            vec![1.0, 0.0, 0.0, 0.0]
+        );
+        let doc_2 = fetched.get("doc_2").expect("doc_2 should be imported");
+        assert_eq!(
+            doc_2.get_vector("embedding").unwrap(),
+            vec![0.0, 1.0, 0.0, 0.0]
+        );
+
+        let results = imported.query(
+            VectorQuery::new("embedding")
+                .topk(10)
+                .vector(&[1.0, 0.0, 0.0, 0.0])?,
+        )?;
+        assert_eq!(results.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_snapshot_schema_only() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let src_path = dir.path().join("src_db");
+        let collection = create_collection(&src_path)?;
+
+        let mut doc = Doc::id("doc_1");
+        doc.set_vector("embedding", &[1.0, 0.0, 0.0, 0.0])?;
+        collection.insert(&[doc])?;
+
+        let snapshot_path = dir.path().join("snapshot.cbor");
+        collection.export_snapshot(&[], &snapshot_path, ExportOptions { schema_only: true })?;
+
+        let dst_path = dir.path().join("dst_db");
+        let imported = Collection::import_snapshot(dst_path, snapshot_path)?;
+
+        let fetched = imported.fetch(&["doc_1"])?;
+        assert!(fetched.get("doc_1").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_snapshot_not_supported_on_open() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        create_collection(&path)?;
+
+        let reopened = open(&path)?;
+        let result = reopened.export_snapshot(
+            &[],
+            dir.path().join("snapshot.cbor"),
+            ExportOptions::default(),
+        );
+        assert!(matches!(result, Err(zvec_bindings::Error::NotSupported(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_spec_round_trips_through_create_index() -> zvec_bindings::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("test_db");
+        let collection = create_collection(&path)?;
+
+        collection.create_index(
+            "embedding",
+            IndexParams::hnsw(16, 200, MetricType::Cosine, QuantizeType::Undefined),
+        )?;
+
+        let spec = IndexSpec::Hnsw {
+            m: 16,
+            ef_construction: 200,
+            metric: MetricType::Cosine,
+            quantize: QuantizeType::Undefined,
+        };
+        let replayed = spec.to_index_params();
+        assert_eq!(replayed.index_type(), IndexType::Hnsw);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_schema_json_round_trip() -> zvec_bindings::Result<()> {
+        let mut schema = CollectionSchema::builder("docs")
+            .int64("id")
+            .string("title")
+            .vector_fp32("embedding", 4)
+            .nullable_float("score")
+            .build()?;
+        schema.register_embedder("title", "embedding")?;
+
+        let json = schema.to_json()?;
+        let restored = CollectionSchema::from_json(&json)?;
+
+        assert_eq!(restored.name(), "docs");
+        assert_eq!(restored.field_count(), schema.field_count());
+        for (original_field, restored_field) in schema.fields().iter().zip(restored.fields()) {
+            assert_eq!(original_field.name(), restored_field.name());
+            assert_eq!(original_field.data_type(), restored_field.data_type());
+            assert_eq!(original_field.dimension(), restored_field.dimension());
+            assert_eq!(original_field.nullable(), restored_field.nullable());
+        }
+        assert_eq!(restored.embedder_mappings().len(), 1);
+        assert_eq!(restored.embedder_mappings()[0].source_field, "title");
+        assert_eq!(restored.embedder_mappings()[0].target_field, "embedding");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_schema_compact_string_round_trip() -> zvec_bindings::Result<()> {
+        let schema = CollectionSchema::builder("docs")
+            .int64("id")
+            .vector_fp32("embedding", 4)
+            .build()?;
+
+        let compact = schema.to_string()?;
+        assert!(!compact.contains('\n'));
+
+        let restored = CollectionSchema::from_string(&compact)?;
+        assert_eq!(restored.name(), "docs");
+        assert_eq!(restored.field_count(), 2);
+
+        Ok(())
+    }
 }
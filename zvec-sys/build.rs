@@ -1,42 +1,275 @@
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use walkdir::WalkDir;
+
+// The `static` feature (default on) builds every thirdparty dependency
+// (arrow, rocksdb, protobuf, lz4, boost, antlr) from source and links it as
+// a whole-archive static lib, matching the libz-sys/rocks-sys convention.
+// With `static` off, `link_system_thirdparty` probes for distro-packaged
+// copies via the `pkg-config` build-dependency instead, which is far
+// faster when those libs are already installed.
+//
+// On MSVC (`target_env = "msvc"`) there's no pkg-config, so thirdparty
+// discovery goes through `vcpkg::Config::find_package` instead (see
+// `link_vcpkg_thirdparty`). Generator selection, parallelism, and the
+// actual `cmake`/build-tool invocation are handled by the `cmake`
+// build-dependency (see `build_zvec`/`build_c_wrapper`) rather than by hand.
 
 const ZVEC_GIT_REF: &str = "v0.2.0";
 
 fn ensure_zvec_source(workspace_dir: &Path) -> PathBuf {
     let zvec_src = workspace_dir.join("vendor/zvec");
+    let git_ref = env::var("ZVEC_GIT_REF").unwrap_or_else(|_| ZVEC_GIT_REF.to_string());
 
     if zvec_src.join("CMakeLists.txt").exists() {
         println!("cargo:warning=zvec source already present");
         return zvec_src;
     }
 
+    if let Ok(tarball) = env::var("ZVEC_VENDOR_TARBALL") {
+        extract_vendor_tarball(Path::new(&tarball), &zvec_src);
+        verify_source_checksum(&zvec_src);
+        return zvec_src;
+    }
+
+    let _ = std::fs::create_dir_all(zvec_src.parent().unwrap());
+
+    if is_commit_sha(&git_ref) {
+        clone_at_commit(&zvec_src, &git_ref);
+    } else {
+        clone_at_ref(&zvec_src, &git_ref);
+    }
+
+    verify_source_checksum(&zvec_src);
+    zvec_src
+}
+
+/// Whether `git_ref` looks like a commit object id rather than a branch or
+/// tag name, so `ensure_zvec_source` knows to fetch it directly instead of
+/// via `git clone --branch` (which only resolves `refs/heads`/`refs/tags`).
+fn is_commit_sha(git_ref: &str) -> bool {
+    (7..=40).contains(&git_ref.len()) && git_ref.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// `git clone --branch <tag>`, same as before - works for tags/branches.
+fn clone_at_ref(zvec_src: &Path, git_ref: &str) {
     println!(
         "cargo:warning=Cloning zvec {} (this may take a few minutes)...",
-        ZVEC_GIT_REF
+        git_ref
     );
-    let _ = std::fs::create_dir_all(zvec_src.parent().unwrap());
-
-    let status = Command::new("git")
-        .args([
+    run_git(
+        Command::new("git").args([
             "clone",
             "--depth",
             "1",
             "--branch",
-            ZVEC_GIT_REF,
+            git_ref,
             "--recursive",
             "https://github.com/alibaba/zvec.git",
             zvec_src.to_str().unwrap(),
-        ])
+        ]),
+        "git clone for zvec",
+    );
+}
+
+/// Pin to an exact commit SHA instead of a tag: `git clone --branch` can't
+/// resolve a bare commit id, so this does `init` + a direct shallow `fetch`
+/// of that object instead, which is what keeps the `--depth 1` behavior.
+fn clone_at_commit(zvec_src: &Path, commit: &str) {
+    println!(
+        "cargo:warning=Fetching zvec commit {} (this may take a few minutes)...",
+        commit
+    );
+    run_git(
+        Command::new("git").args(["init", "-q", zvec_src.to_str().unwrap()]),
+        "git init for zvec",
+    );
+    run_git(
+        Command::new("git").current_dir(zvec_src).args([
+            "remote",
+            "add",
+            "origin",
+            "https://github.com/alibaba/zvec.git",
+        ]),
+        "git remote add for zvec",
+    );
+    run_git(
+        Command::new("git")
+            .current_dir(zvec_src)
+            .args(["fetch", "--depth", "1", "origin", commit]),
+        "git fetch for zvec",
+    );
+    run_git(
+        Command::new("git")
+            .current_dir(zvec_src)
+            .args(["checkout", "FETCH_HEAD"]),
+        "git checkout for zvec",
+    );
+    run_git(
+        Command::new("git").current_dir(zvec_src).args([
+            "submodule",
+            "update",
+            "--init",
+            "--recursive",
+            "--depth",
+            "1",
+        ]),
+        "git submodule update for zvec",
+    );
+}
+
+fn run_git(cmd: &mut Command, context: &str) {
+    let status = cmd
         .status()
-        .expect("Failed to execute git clone. Please ensure git is installed.");
+        .unwrap_or_else(|_| panic!("Failed to execute command: {}", context));
+    if !status.success() {
+        panic!(
+            "{} failed. Please check your network connection and that git is installed.",
+            context
+        );
+    }
+}
+
+/// Unpack a `ZVEC_VENDOR_TARBALL` in place of the `git clone`, for offline
+/// or air-gapped builds that ship the zvec source as an artifact instead of
+/// fetching it from GitHub. Expects the archive to contain a single
+/// top-level directory (stripped via `--strip-components=1`), same as a
+/// GitHub source tarball.
+fn extract_vendor_tarball(tarball: &Path, dest: &Path) {
+    println!(
+        "cargo:warning=Extracting vendored zvec source from {}",
+        tarball.display()
+    );
+    std::fs::create_dir_all(dest).expect("Failed to create vendor/zvec directory");
+
+    let status = Command::new("tar")
+        .args(["xf"])
+        .arg(tarball)
+        .args(["--strip-components=1", "-C"])
+        .arg(dest)
+        .status()
+        .expect("Failed to execute tar. Please ensure tar is installed.");
 
     if !status.success() {
-        panic!("git clone failed. Please check your network connection and that git is installed.");
+        panic!(
+            "Failed to extract ZVEC_VENDOR_TARBALL={}",
+            tarball.display()
+        );
     }
+}
 
-    zvec_src
+/// Verify the freshly-fetched `zvec_src` tree against the digest pinned in
+/// `zvec.lock`, so a retagged upstream or a tampered-with clone fails the
+/// build instead of silently compiling a different C++ library than the one
+/// reviewed. `ZVEC_ALLOW_DIRTY` skips this for local development against a
+/// patched vendor tree.
+///
+/// An unpinned or missing `zvec.lock` is a hard error, not trust-on-first-use:
+/// a real `zvec.lock` is expected to already be committed for
+/// `ZVEC_GIT_REF`, so a build that can't find a pin has nothing to verify
+/// against and refuses to silently accept whatever was just fetched.
+/// `ZVEC_BOOTSTRAP_LOCK` is the explicit, separate opt-in for generating a
+/// new pin from the tree that was just fetched (e.g. after bumping
+/// `ZVEC_GIT_REF`) - it must be reviewed and committed before it counts as
+/// verification for anyone else.
+fn verify_source_checksum(zvec_src: &Path) {
+    if env::var("ZVEC_ALLOW_DIRTY").is_ok() {
+        println!(
+            "cargo:warning=ZVEC_ALLOW_DIRTY set; skipping vendored source checksum verification"
+        );
+        return;
+    }
+
+    let actual = hash_source_tree(zvec_src);
+    let lock_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("zvec.lock");
+
+    match read_pinned_checksum(&lock_path) {
+        Some(expected) => {
+            if actual != expected {
+                panic!(
+                    "Vendored zvec source at {} does not match the checksum pinned in {} \
+                     (expected {}, got {}). This can mean upstream was retagged or the fetch \
+                     was tampered with in transit - delete vendor/zvec and re-fetch, or set \
+                     ZVEC_ALLOW_DIRTY=1 if you're intentionally developing against a local patch.",
+                    zvec_src.display(),
+                    lock_path.display(),
+                    expected,
+                    actual
+                );
+            }
+        }
+        None if env::var("ZVEC_BOOTSTRAP_LOCK").is_ok() => {
+            std::fs::write(&lock_path, format!("{}\n", actual)).expect("Failed to write zvec.lock");
+            println!(
+                "cargo:warning=ZVEC_BOOTSTRAP_LOCK set; wrote a new pinned checksum to {} - review and commit it",
+                lock_path.display()
+            );
+        }
+        None => {
+            panic!(
+                "{} has no checksum pinned for this source - refusing to trust an unverified \
+                 fetch on first build. Commit a real {} pinning the reviewed zvec source, or set \
+                 ZVEC_BOOTSTRAP_LOCK=1 to generate one from the tree that was just fetched (only \
+                 for intentionally bootstrapping a new pin, e.g. after bumping ZVEC_GIT_REF - the \
+                 result must still be reviewed and committed before anyone else's build trusts it).",
+                lock_path.display(),
+                lock_path.display()
+            );
+        }
+    }
+}
+
+/// Read the digest pinned in `zvec.lock`, ignoring blank lines and `#`
+/// comment lines so the committed file can carry an explanatory header.
+/// Returns `None` if the file is missing or has no non-comment content.
+fn read_pinned_checksum(lock_path: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(lock_path).ok()?;
+    contents
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+}
+
+/// Hash the checked-out zvec tree deterministically: a SHA-256 over each
+/// file's own SHA-256 paired with its path, walked in sorted order so the
+/// result doesn't depend on directory-listing order. Skips `.git`, whose
+/// contents (refs, pack layout) vary with how the tree was fetched even
+/// when the checked-out files are identical.
+fn hash_source_tree(root: &Path) -> String {
+    let mut files = Vec::new();
+    collect_source_files(root, root, &mut files);
+    files.sort();
+
+    let mut manifest = Sha256::new();
+    for relative_path in &files {
+        let contents = std::fs::read(root.join(relative_path))
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", relative_path.display(), e));
+        manifest.update(relative_path.to_string_lossy().as_bytes());
+        manifest.update(b"\0");
+        manifest.update(Sha256::digest(&contents));
+        manifest.update(b"\n");
+    }
+    format!("{:x}", manifest.finalize())
+}
+
+fn collect_source_files(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", dir.display(), e));
+    for entry in entries {
+        let path = entry.expect("Failed to read directory entry").path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name == ".git") {
+                continue;
+            }
+            collect_source_files(root, &path, out);
+        } else {
+            out.push(path.strip_prefix(root).unwrap().to_path_buf());
+        }
+    }
 }
 
 fn main() {
@@ -46,211 +279,300 @@ fn main() {
 
     println!("cargo:rerun-if-env-changed=ZVEC_GIT_REF");
     println!("cargo:rerun-if-env-changed=ZVEC_BUILD_TYPE");
-    println!("cargo:rerun-if-env-changed=ZVEC_BUILD_PARALLEL");
     println!("cargo:rerun-if-env-changed=ZVEC_CPU_ARCH");
     println!("cargo:rerun-if-env-changed=ZVEC_OPENMP");
+    println!("cargo:rerun-if-env-changed=ZVEC_CMAKE_TOOLCHAIN");
+    println!("cargo:rerun-if-env-changed=ZVEC_PREBUILT_DIR");
+    println!("cargo:rerun-if-env-changed=ZVEC_VENDOR_TARBALL");
+    println!("cargo:rerun-if-env-changed=ZVEC_ALLOW_DIRTY");
     println!("cargo:rerun-if-changed=zvec-c-wrapper/CMakeLists.txt");
     println!("cargo:rerun-if-changed=zvec-c-wrapper/include/zvec_c.h");
     println!("cargo:rerun-if-changed=zvec-c-wrapper/include/zvec_c_internal.h");
     println!("cargo:rerun-if-changed=zvec-c-wrapper/src");
 
-    let zvec_src = ensure_zvec_source(workspace_dir);
-    patch_antlr_cmake(&zvec_src);
-    let zvec_build = zvec_src.join("build");
-    let zvec_lib = zvec_build.join("lib");
+    let target = env::var("TARGET").expect("TARGET not set");
+    let wrapper_dir = manifest_dir.join("zvec-c-wrapper");
+
+    let (zvec_lib, wrapper_build) = if let Ok(prebuilt) = env::var("ZVEC_PREBUILT_DIR") {
+        prebuilt_libs(Path::new(&prebuilt))
+    } else {
+        let host = env::var("HOST").unwrap_or_default();
+        let toolchain_file = cmake_toolchain_file(&out_dir, &host, &target);
 
-    let build_type = env::var("ZVEC_BUILD_TYPE").unwrap_or_else(|_| "Release".to_string());
-    let parallel_jobs = env::var("ZVEC_BUILD_PARALLEL")
-        .map(|s| s.parse::<usize>().unwrap_or_else(|_| num_cpus()))
-        .unwrap_or_else(|_| num_cpus());
+        let zvec_src = ensure_zvec_source(workspace_dir);
+        patch_antlr_cmake(&zvec_src);
 
-    let wrapper_dir = manifest_dir.join("zvec-c-wrapper");
-    let wrapper_build = out_dir.join("zvec-c-wrapper-build");
+        let build_type = env::var("ZVEC_BUILD_TYPE").unwrap_or_else(|_| "Release".to_string());
 
-    let zvec_built = zvec_lib.join("libzvec_db.a");
-    if !zvec_built.exists() {
         println!("cargo:warning=Building zvec C++ library...");
-        build_zvec(&zvec_src, &zvec_build, &build_type, parallel_jobs);
-    } else {
-        println!("cargo:warning=zvec C++ library already built");
-    }
+        let zvec_dst = build_zvec(&zvec_src, &build_type, toolchain_file.as_deref());
+        // `cmake::Config` builds out-of-tree under `<dst>/build`; that's
+        // the tree `discover_thirdparty_libs` walks for bundled thirdparty
+        // static libs.
+        let zvec_build = zvec_dst.join("build");
+        let zvec_lib = zvec_build.join("lib");
+
+        println!("cargo:warning=Building C wrapper...");
+        let wrapper_dst = build_c_wrapper(
+            &wrapper_dir,
+            &zvec_src,
+            &build_type,
+            toolchain_file.as_deref(),
+        );
+        let wrapper_build = wrapper_dst.join("build");
+
+        (zvec_lib, wrapper_build)
+    };
+
+    generate_bindings(&wrapper_dir, &target);
+
+    link_libraries(&zvec_lib, &wrapper_build, &wrapper_dir);
+}
 
-    println!("cargo:warning=Building C wrapper...");
-    build_c_wrapper(
-        &wrapper_dir,
-        &wrapper_build,
-        &zvec_src,
-        &build_type,
-        parallel_jobs,
+/// Skip `ensure_zvec_source`, `build_zvec`, and `build_c_wrapper` entirely
+/// when pointed at an already-built tree (mirrors grpcio-sys's prebuilt
+/// env-var knobs), which is the common path for CI caches and offline
+/// builds that can't afford the from-scratch C++ build every time. Expects
+/// `prebuilt/lib` to hold both `libzvec_db.a` (which `build_zvec` leaves
+/// under `<dst>/build/lib`) and `libzvec_c_wrapper.a` (which
+/// `build_c_wrapper` leaves under its own `<dst>/build`).
+fn prebuilt_libs(prebuilt: &Path) -> (PathBuf, PathBuf) {
+    let lib = prebuilt.join("lib");
+    if !lib.join("libzvec_db.a").exists() {
+        panic!(
+            "ZVEC_PREBUILT_DIR={} does not contain lib/libzvec_db.a. Point it at a directory \
+             laid out like a previous zvec + C-wrapper build (a `lib/` holding the zvec and \
+             wrapper static libs), or unset ZVEC_PREBUILT_DIR to build from source.",
+            prebuilt.display()
+        );
+    }
+    println!(
+        "cargo:warning=Using prebuilt zvec tree at {}, skipping clone and build",
+        prebuilt.display()
     );
+    (lib.clone(), lib)
+}
 
-    generate_bindings(&wrapper_dir);
+/// Resolve the CMake toolchain file to pass to both cmake invocations, so
+/// cross builds (`TARGET` != `HOST`) compile the C++ side for the right
+/// arch instead of silently producing a host build.
+///
+/// Honors `ZVEC_CMAKE_TOOLCHAIN` if the user already has one (e.g. a vcpkg
+/// or Yocto toolchain file); otherwise auto-generates a minimal one that
+/// just points `CMAKE_C_COMPILER`/`CMAKE_CXX_COMPILER` at the target
+/// triple's cross-gcc. Returns `None` for a native build.
+fn cmake_toolchain_file(out_dir: &Path, host: &str, target: &str) -> Option<PathBuf> {
+    if let Ok(path) = env::var("ZVEC_CMAKE_TOOLCHAIN") {
+        return Some(PathBuf::from(path));
+    }
+
+    if host == target {
+        return None;
+    }
 
-    link_libraries(&zvec_lib, &wrapper_build);
+    let system_name = if target.contains("windows") {
+        "Windows"
+    } else if target.contains("darwin") || target.contains("ios") {
+        "Darwin"
+    } else {
+        "Linux"
+    };
+    let processor = target.split('-').next().unwrap_or("x86_64");
+
+    let contents = format!(
+        "set(CMAKE_SYSTEM_NAME {system_name})\n\
+         set(CMAKE_SYSTEM_PROCESSOR {processor})\n\
+         set(CMAKE_C_COMPILER {target}-gcc)\n\
+         set(CMAKE_CXX_COMPILER {target}-g++)\n\
+         set(CMAKE_FIND_ROOT_PATH_MODE_PROGRAM NEVER)\n\
+         set(CMAKE_FIND_ROOT_PATH_MODE_LIBRARY ONLY)\n\
+         set(CMAKE_FIND_ROOT_PATH_MODE_INCLUDE ONLY)\n\
+         set(CMAKE_FIND_ROOT_PATH_MODE_PACKAGE ONLY)\n"
+    );
+    let path = out_dir.join("zvec-toolchain.cmake");
+    std::fs::write(&path, contents).expect("Failed to write auto-generated CMake toolchain file");
+    println!(
+        "cargo:warning=Cross-compiling for {} (host {}); generated toolchain file at {}",
+        target,
+        host,
+        path.display()
+    );
+    Some(path)
 }
 
-fn build_zvec(_src: &Path, build: &Path, build_type: &str, parallel_jobs: usize) {
-    let _ = std::fs::create_dir_all(build);
+/// Configure and build the vendored zvec C++ library via the `cmake`
+/// build-dependency rather than shelling out to `cmake`/`make` by hand:
+/// it auto-selects the generator (Ninja/Makefiles/MSVC, per host), forwards
+/// cargo's target/profile and parallelism, and sets
+/// `CMAKE_POSITION_INDEPENDENT_CODE` for us. Returns the install directory;
+/// the raw build tree (what `discover_thirdparty_libs` walks for thirdparty
+/// static libs) lives under `<dst>/build`.
+fn build_zvec(src: &Path, build_type: &str, toolchain_file: Option<&Path>) -> PathBuf {
+    let mut config = cmake::Config::new(src);
+    config
+        .profile(build_type)
+        .define("BUILD_PYTHON_BINDINGS", "OFF")
+        .define("BUILD_TOOLS", "OFF")
+        .define("CMAKE_POLICY_VERSION_MINIMUM", "3.5")
+        .build_target("zvec_db");
+
+    if let Some(toolchain) = toolchain_file {
+        config.define("CMAKE_TOOLCHAIN_FILE", toolchain);
+    }
 
-    let mut cmake_args = vec![
-        format!("-DCMAKE_BUILD_TYPE={}", build_type),
-        "-DBUILD_PYTHON_BINDINGS=OFF".to_string(),
-        "-DBUILD_TOOLS=OFF".to_string(),
-        "-DCMAKE_POLICY_VERSION_MINIMUM=3.5".to_string(),
-    ];
+    if !cfg!(feature = "static") {
+        // Ask zvec's own CMake build to link its bundled thirdparty deps
+        // against the system copies we'll pkg-config/vcpkg-probe for in
+        // `link_system_thirdparty`/`link_vcpkg_thirdparty`, instead of
+        // building them from source.
+        config.define("ZVEC_USE_SYSTEM_DEPS", "ON");
+    }
 
     if let Ok(arch) = env::var("ZVEC_CPU_ARCH") {
-        cmake_args.push(format!("-DENABLE_{}=ON", arch));
+        config.define(format!("ENABLE_{}", arch), "ON");
     }
 
     if env::var("ZVEC_OPENMP")
         .map(|v| v == "ON" || v == "1")
         .unwrap_or(false)
     {
-        cmake_args.push("-DENABLE_OPENMP=ON".to_string());
+        config.define("ENABLE_OPENMP", "ON");
     }
 
-    cmake_args.push("..".to_string());
-
-    run(
-        Command::new("cmake").current_dir(build).args(&cmake_args),
-        "cmake configure for zvec",
-    );
-
-    run(
-        Command::new("make")
-            .current_dir(build)
-            .args(["-j", parallel_jobs.to_string().as_str()]),
-        "make for zvec",
-    );
+    config.build()
 }
 
+/// Configure and build the C wrapper the same way as `build_zvec`. Returns
+/// the install directory; `<dst>/build` is the raw build tree that holds
+/// `libzvec_c_wrapper.a`.
 fn build_c_wrapper(
     wrapper_dir: &Path,
-    build: &Path,
     zvec_src: &Path,
     build_type: &str,
-    parallel_jobs: usize,
-) {
-    let _ = std::fs::create_dir_all(build);
-
-    run(
-        Command::new("cmake").current_dir(build).args([
-            format!("-DZVEC_SRC_DIR={}", zvec_src.display()).as_str(),
-            format!("-DCMAKE_BUILD_TYPE={}", build_type).as_str(),
-            wrapper_dir.to_str().expect("Invalid wrapper dir path"),
-        ]),
-        "cmake configure for C wrapper",
-    );
+    toolchain_file: Option<&Path>,
+) -> PathBuf {
+    let mut config = cmake::Config::new(wrapper_dir);
+    config
+        .profile(build_type)
+        .define("ZVEC_SRC_DIR", zvec_src)
+        .build_target("zvec_c_wrapper");
+
+    if let Some(toolchain) = toolchain_file {
+        config.define("CMAKE_TOOLCHAIN_FILE", toolchain);
+    }
 
-    run(
-        Command::new("make")
-            .current_dir(build)
-            .args(["-j", parallel_jobs.to_string().as_str()]),
-        "make for C wrapper",
-    );
+    config.build()
 }
 
-fn generate_bindings(wrapper_dir: &Path) {
+fn generate_bindings(wrapper_dir: &Path, target: &str) {
     let header = wrapper_dir.join("include/zvec_c.h");
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
 
-    let bindings = bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default()
         .header(header.to_str().expect("Invalid header path"))
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .generate_comments(true)
+        .clang_arg(format!("--target={}", target))
         .clang_arg("-I/usr/include")
-        .clang_arg("-I/usr/local/include")
-        .clang_arg("-I/usr/lib/gcc/aarch64-linux-gnu/13/include")
-        .clang_arg("-I/usr/include/c++/13")
-        .clang_arg("-I/usr/include/aarch64-linux-gnu/c++/13")
-        .generate()
-        .expect("Unable to generate bindings");
+        .clang_arg("-I/usr/local/include");
+
+    for include in target_clang_include_args(target) {
+        builder = builder.clang_arg(include);
+    }
+
+    let bindings = builder.generate().expect("Unable to generate bindings");
 
     bindings
         .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write bindings!");
 }
 
-fn link_libraries(zvec_lib: &Path, wrapper_build: &Path) {
-    // C wrapper
-    println!("cargo:rustc-link-search=native={}", wrapper_build.display());
-    println!("cargo:rustc-link-lib=static=zvec_c_wrapper");
+/// Derive the `-I` clang args bindgen needs for `target`'s libstdc++/libc
+/// headers instead of the literal `aarch64-linux-gnu` paths this used to
+/// hardcode, so cross builds parse the right target's headers.
+///
+/// Finds the gcc include dir by asking `<target>-gcc` directly via
+/// `-print-search-dirs`, falling back to scanning the conventional
+/// `/usr/lib/gcc/<target>` layout Debian/Ubuntu multiarch toolchains use.
+/// The newest `/usr/include/c++` version (and its `<target>` subdir, if
+/// present) is added either way.
+fn target_clang_include_args(target: &str) -> Vec<String> {
+    let mut args = Vec::new();
+
+    if let Some(install_dir) = gcc_install_dir(target) {
+        let include_dir = install_dir.join("include");
+        if include_dir.is_dir() {
+            args.push(format!("-I{}", include_dir.display()));
+        }
+    } else if let Some(gcc_include) =
+        newest_subdir(&PathBuf::from(format!("/usr/lib/gcc/{}", target)))
+    {
+        args.push(format!("-I{}", gcc_include.join("include").display()));
+    }
 
-    // zvec component libraries path
-    println!("cargo:rustc-link-search=native={}", zvec_lib.display());
+    if let Some(cxx_dir) = newest_subdir(Path::new("/usr/include/c++")) {
+        args.push(format!("-I{}", cxx_dir.display()));
+        let target_cxx_dir = cxx_dir.join(target);
+        if target_cxx_dir.is_dir() {
+            args.push(format!("-I{}", target_cxx_dir.display()));
+        }
+    }
 
-    // External third-party libraries (built in build/external/usr/local/lib)
-    let external_lib = zvec_lib.parent().unwrap().join("external/usr/local/lib");
-    println!("cargo:rustc-link-search=native={}", external_lib.display());
+    args
+}
 
-    // Arrow build directory (contains thrift and many other libs)
-    let arrow_build = zvec_lib
-        .parent()
-        .unwrap()
-        .join("thirdparty/arrow/arrow/src/ARROW.BUILD-build");
-    println!(
-        "cargo:rustc-link-search=native={}",
-        arrow_build.join("lib").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        arrow_build.join("release").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        arrow_build.join("re2_ep-install/lib").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        arrow_build.join("utf8proc_ep-install/lib").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        arrow_build
-            .join("zlib_ep/src/zlib_ep-install/lib")
-            .display()
-    );
+/// Ask `<target>-gcc` where it's installed via `-print-search-dirs`, for
+/// targets where a cross-gcc is on `PATH` but doesn't live under the
+/// conventional `/usr/lib/gcc/<target>` path.
+fn gcc_install_dir(target: &str) -> Option<PathBuf> {
+    let output = Command::new(format!("{}-gcc", target))
+        .arg("-print-search-dirs")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let install_line = stdout.lines().find(|l| l.starts_with("install:"))?;
+    Some(PathBuf::from(
+        install_line.trim_start_matches("install:").trim(),
+    ))
+}
 
-    // Boost libraries
-    let boost_build = arrow_build.join("_deps/boost-build/libs");
-    println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("atomic").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("charconv").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("chrono").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("container").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("date_time").display()
-    );
-    println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("locale").display()
-    );
+/// Pick the lexicographically-last (i.e. newest-versioned) direct
+/// subdirectory of `parent`, e.g. resolving `/usr/include/c++` to
+/// `/usr/include/c++/13`.
+fn newest_subdir(parent: &Path) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(parent)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    candidates.sort();
+    candidates.pop()
+}
+
+fn link_libraries(zvec_lib: &Path, wrapper_build: &Path, wrapper_dir: &Path) {
+    // `-sys` crate metadata for downstream build scripts (surfaced to them
+    // as `DEP_ZVEC_ROOT`/`DEP_ZVEC_INCLUDE`/`DEP_ZVEC_LIB`, same as
+    // `libz-sys`/`openssl-sys`), so a crate linking straight against the C
+    // API doesn't have to re-derive where our build put it.
     println!(
-        "cargo:rustc-link-search=native={}",
-        boost_build.join("thread").display()
+        "cargo:root={}",
+        zvec_lib.parent().unwrap_or(zvec_lib).display()
     );
+    println!("cargo:include={}", wrapper_dir.join("include").display());
+    println!("cargo:lib={}", zvec_lib.display());
+
+    // C wrapper
+    println!("cargo:rustc-link-search=native={}", wrapper_build.display());
+    println!("cargo:rustc-link-lib=static=zvec_c_wrapper");
 
-    // LZ4
-    let lz4_build = zvec_lib
-        .parent()
-        .unwrap()
-        .join("thirdparty/lz4/lz4/src/Lz4.BUILD/lib");
-    println!("cargo:rustc-link-search=native={}", lz4_build.display());
+    // zvec component libraries path
+    println!("cargo:rustc-link-search=native={}", zvec_lib.display());
 
-    // All libraries as whole-archive to ensure they're linked in tests
+    // zvec's own libraries, whole-archive so they're linked in tests too
     // (Cargo doesn't propagate regular static lib links to test binaries)
     // Note: zvec_core.a bundles core_knn_* libraries
     // Note: zvec_db.a bundles zvec_common, zvec_index, zvec_proto, zvec_sqlengine
@@ -259,37 +581,20 @@ fn link_libraries(zvec_lib: &Path, wrapper_build: &Path) {
         println!("cargo:rustc-link-lib=static:+whole-archive={}", lib);
     }
 
-    // Third-party dependencies (whole-archive for test linking)
-    // Note: 'z', 'utf8proc', 're2', 'thrift' are included in arrow_bundled_dependencies
-    let thirdparty_libs = [
-        "parquet",
-        "arrow_acero",
-        "arrow_dataset",
-        "arrow_compute",
-        "arrow",
-        "arrow_bundled_dependencies",
-        "roaring",
-        "rocksdb",
-        "lz4",
-        "protobuf",
-        "protoc",
-        "boost_thread",
-        "boost_atomic",
-        "boost_chrono",
-        "boost_container",
-        "boost_date_time",
-        "boost_locale",
-        "boost_charconv",
-        "glog",
-        "gflags_nothreads",
-        "antlr4-runtime",
-    ];
-    for lib in &thirdparty_libs {
-        println!("cargo:rustc-link-lib=static:+whole-archive={}", lib);
+    if cfg!(target_env = "msvc") {
+        link_vcpkg_thirdparty();
+    } else if cfg!(feature = "static") {
+        discover_thirdparty_libs(zvec_lib.parent().unwrap_or(zvec_lib));
+    } else {
+        link_system_thirdparty();
     }
 
     // System libraries
-    if cfg!(target_os = "macos") {
+    if cfg!(target_env = "msvc") {
+        println!("cargo:rustc-link-lib=shlwapi");
+        println!("cargo:rustc-link-lib=rpcrt4");
+        println!("cargo:rustc-link-lib=ws2_32");
+    } else if cfg!(target_os = "macos") {
         println!("cargo:rustc-link-lib=c++");
         println!("cargo:rustc-link-lib=m");
     } else {
@@ -300,39 +605,158 @@ fn link_libraries(zvec_lib: &Path, wrapper_build: &Path) {
     }
 }
 
-fn run(cmd: &mut Command, context: &str) {
-    println!("cargo:warning=Running: {:?}", cmd);
-    let status = cmd.status().unwrap_or_else(|_| {
-        panic!("Failed to execute command: {}", context);
-    });
-    if !status.success() {
-        panic!("Command failed ({}): {:?}", context, cmd);
+/// zvec's own libraries: already link-searched and whole-archived
+/// explicitly by `link_libraries`, so `discover_thirdparty_libs` skips them
+/// to avoid emitting duplicate link lines.
+const ZVEC_OWN_LIBS: &[&str] = &["zvec_core", "zvec_ailego", "zvec_db"];
+
+/// Link against the bundled-from-source thirdparty static libs built by
+/// zvec's own CMake build (the default `static` feature path), discovered
+/// by walking `zvec_build` (covering both `external/` and `thirdparty/`)
+/// rather than hardcoding each dependency's build path. Upstream's
+/// FetchContent/CMake layout for arrow/boost/lz4 has shifted between zvec
+/// versions before (`arrow/src/ARROW.BUILD-build`, `_deps/boost-build/libs/
+/// <name>`, ...), so walking once and deriving both the link-search dirs
+/// and the link names from whatever `lib*.a` files actually exist is far
+/// more resilient than re-guessing those paths per version, mirroring how
+/// `grpcio-sys` discovers its own bundled libs.
+fn discover_thirdparty_libs(zvec_build: &Path) {
+    let mut search_dirs = BTreeSet::new();
+    let mut libs = BTreeSet::new();
+
+    for entry in WalkDir::new(zvec_build)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+    {
+        let Some(link_name) = entry
+            .file_name()
+            .to_str()
+            .and_then(static_lib_link_name)
+            .filter(|name| !ZVEC_OWN_LIBS.contains(&name.as_str()))
+        else {
+            continue;
+        };
+
+        if let Some(parent) = entry.path().parent() {
+            search_dirs.insert(parent.to_path_buf());
+        }
+        libs.insert(link_name);
+    }
+
+    for dir in &search_dirs {
+        println!("cargo:rustc-link-search=native={}", dir.display());
+    }
+    // Whole-archive so these get pulled into test binaries too (Cargo
+    // doesn't propagate regular static lib links to test binaries).
+    for lib in &libs {
+        println!("cargo:rustc-link-lib=static:+whole-archive={}", lib);
     }
 }
 
-fn num_cpus() -> usize {
-    std::thread::available_parallelism()
-        .map(|p| p.get())
-        .unwrap_or(4)
+/// Recognize a Unix static archive filename (`lib<name>.a`) and return
+/// `<name>`, the link name `rustc-link-lib` expects.
+fn static_lib_link_name(filename: &str) -> Option<String> {
+    filename
+        .strip_prefix("lib")
+        .and_then(|rest| rest.strip_suffix(".a"))
+        .map(str::to_string)
+}
+
+/// Link against distro-packaged thirdparty libs via pkg-config instead of
+/// zvec's bundled-from-source build (the `static = false` path). Falls back
+/// to a warning (and no link line) for anything pkg-config can't find,
+/// rather than failing the build outright - the bundled build is still the
+/// supported fallback for those.
+fn link_system_thirdparty() {
+    let pkgs = [
+        "arrow",
+        "parquet",
+        "rocksdb",
+        "lz4",
+        "protobuf",
+        "boost_thread",
+        "boost_atomic",
+        "boost_chrono",
+        "boost_container",
+        "boost_date_time",
+        "boost_locale",
+        "boost_charconv",
+    ];
+    for pkg in pkgs {
+        match pkg_config::Config::new().cargo_metadata(false).probe(pkg) {
+            Ok(library) => {
+                for search_path in &library.link_paths {
+                    println!("cargo:rustc-link-search=native={}", search_path.display());
+                }
+                for lib in &library.libs {
+                    println!("cargo:rustc-link-lib=dylib={}", lib);
+                }
+            }
+            Err(err) => {
+                println!(
+                    "cargo:warning=pkg-config could not find `{}` ({}); this link will be missing unless it's provided another way",
+                    pkg, err
+                );
+            }
+        }
+    }
+}
+
+/// Link against vcpkg-installed thirdparty libs on MSVC (mirroring
+/// libz-sys's `try_vcpkg`). `vcpkg::Config::find_package` emits its own
+/// `cargo:rustc-link-lib`/`cargo:rustc-link-search` lines on success, so
+/// there's nothing further to print there.
+fn link_vcpkg_thirdparty() {
+    let pkgs = [
+        "arrow",
+        "parquet",
+        "rocksdb",
+        "protobuf",
+        "boost-thread",
+        "boost-chrono",
+        "boost-date-time",
+        "boost-locale",
+    ];
+    for pkg in pkgs {
+        if let Err(err) = vcpkg::Config::new().find_package(pkg) {
+            println!(
+                "cargo:warning=vcpkg could not find `{}` ({}); this link will be missing unless it's provided another way",
+                pkg, err
+            );
+        }
+    }
 }
 
 fn patch_antlr_cmake(zvec_src: &Path) {
-    let cmake_path = zvec_src
-        .join("thirdparty/antlr/antlr4/runtime/Cpp/CMakeLists.txt");
+    let cmake_path = zvec_src.join("thirdparty/antlr/antlr4/runtime/Cpp/CMakeLists.txt");
     if !cmake_path.exists() {
         return;
     }
-    let content = std::fs::read_to_string(&cmake_path)
-        .expect("Failed to read ANTLR CMakeLists.txt");
+    let content =
+        std::fs::read_to_string(&cmake_path).expect("Failed to read ANTLR CMakeLists.txt");
     // Modern CMake (3.30+) rejects deprecated OLD policy settings.
     let patched = content
-        .replace("CMAKE_POLICY(SET CMP0054 OLD)", "CMAKE_POLICY(SET CMP0054 NEW)")
-        .replace("CMAKE_POLICY(SET CMP0045 OLD)", "CMAKE_POLICY(SET CMP0045 NEW)")
-        .replace("CMAKE_POLICY(SET CMP0042 OLD)", "CMAKE_POLICY(SET CMP0042 NEW)")
-        .replace("CMAKE_POLICY(SET CMP0059 OLD)", "CMAKE_POLICY(SET CMP0059 NEW)");
+        .replace(
+            "CMAKE_POLICY(SET CMP0054 OLD)",
+            "CMAKE_POLICY(SET CMP0054 NEW)",
+        )
+        .replace(
+            "CMAKE_POLICY(SET CMP0045 OLD)",
+            "CMAKE_POLICY(SET CMP0045 NEW)",
+        )
+        .replace(
+            "CMAKE_POLICY(SET CMP0042 OLD)",
+            "CMAKE_POLICY(SET CMP0042 NEW)",
+        )
+        .replace(
+            "CMAKE_POLICY(SET CMP0059 OLD)",
+            "CMAKE_POLICY(SET CMP0059 NEW)",
+        );
     if patched != content {
-        std::fs::write(&cmake_path, patched)
-            .expect("Failed to patch ANTLR CMakeLists.txt");
-        println!("cargo:warning=Patched ANTLR CMakeLists.txt for modern CMake policy compatibility");
+        std::fs::write(&cmake_path, patched).expect("Failed to patch ANTLR CMakeLists.txt");
+        println!(
+            "cargo:warning=Patched ANTLR CMakeLists.txt for modern CMake policy compatibility"
+        );
     }
 }